@@ -1,37 +1,89 @@
+pub mod printer;
+
+use std::sync::atomic::{AtomicUsize, Ordering};
+
 use crate::{
     lexer::tokens::{Span, TokenKind},
+    source_map::SourceMap,
     types::{annotated_type::AnnotatedType, return_type::ReturnType},
 };
 
-#[derive(Debug)]
+#[derive(Debug, Clone, PartialEq)]
 pub struct ZastProgram {
     pub body: Vec<Statement>,
 }
 
-#[derive(Debug)]
+#[derive(Debug, Clone, PartialEq)]
 pub struct FunctionParameter {
     pub name: String,
     pub annotated_type: AnnotatedType,
     pub span: Span,
 }
 
+/// A call argument, optionally named after the parameter it fills, e.g. the
+/// `b: 2` in `foo(1, b: 2)`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Argument {
+    pub name: Option<String>,
+    pub value: Expression,
+    pub span: Span,
+}
+
 pub type Expression = Spanned<Expr>;
-#[derive(Debug)]
+#[derive(Debug, Clone, PartialEq)]
 pub enum Expr {
-    IntegerLiteral(i64),
-    FloatLiteral(f64),
+    IntegerLiteral {
+        value: i64,
+        suffix: Option<AnnotatedType>, // e.g. `u8` in `200u8`
+    },
+    FloatLiteral {
+        value: f64,
+        suffix: Option<AnnotatedType>,
+    },
+    CharLiteral(char),
+    StringLiteral(String),
     Identifier(String),
     Address(Box<Expression>),
     Dereference(Box<Expression>),
+    Negate(Box<Expression>),
     BinaryExpression {
         left: Box<Expression>,
         operator: TokenKind,
         right: Box<Expression>,
     },
+    Index {
+        base: Box<Expression>,
+        index: Box<Expression>,
+    },
+    FieldAccess {
+        base: Box<Expression>,
+        field: String,
+    },
+    EnumVariant {
+        enum_name: String,
+        variant: String,
+    },
+    Assignment {
+        target: Box<Expression>,
+        value: Box<Expression>,
+    },
+    Ternary {
+        condition: Box<Expression>,
+        then_expr: Box<Expression>,
+        else_expr: Box<Expression>,
+    },
+    Call {
+        callee: Box<Expression>,
+        arguments: Vec<Argument>,
+    },
+    Cast {
+        expr: Box<Expression>,
+        target: AnnotatedType,
+    },
 }
 
 pub type Statement = Spanned<Stmt>;
-#[derive(Debug)]
+#[derive(Debug, Clone, PartialEq)]
 pub enum Stmt {
     FunctionDeclaration {
         name: String,
@@ -41,6 +93,7 @@ pub enum Stmt {
     },
     BlockStatement {
         statements: Vec<Box<Statement>>,
+        tail: Option<Box<Expression>>, // trailing expression without ';'
     },
     Expression {
         expression: Expression,
@@ -48,24 +101,560 @@ pub enum Stmt {
     VariableDeclaration {
         mutable: bool,
         identifier: String,
-        annotated_type: AnnotatedType,
+        annotated_type: Option<AnnotatedType>,
         value: Expression,
     },
+    Import {
+        path: String,
+    },
+    StructDeclaration {
+        name: String,
+        fields: Vec<FunctionParameter>,
+    },
+    EnumDeclaration {
+        name: String,
+        variants: Vec<String>,
+    },
+    Return {
+        value: Option<Expression>,
+    },
+    If {
+        condition: Expression,
+        then_branch: Box<Statement>,
+        else_branch: Option<Box<Statement>>,
+    },
+    While {
+        condition: Expression,
+        body: Box<Statement>,
+    },
 }
 
 impl Stmt {
     pub fn spanned(self, span: Span) -> Spanned<Self> {
-        Spanned { node: self, span }
+        Spanned {
+            node: self,
+            span,
+            id: NodeId::fresh(),
+        }
     }
 }
 
 impl Expr {
     pub fn spanned(self, span: Span) -> Spanned<Self> {
-        Spanned { node: self, span }
+        Spanned {
+            node: self,
+            span,
+            id: NodeId::fresh(),
+        }
+    }
+}
+
+/// Identity of a single AST node, assigned when the node is built.
+///
+/// Spans can't tell nodes apart: desugaring `x += 1` yields an assignment and
+/// an addition covering the same source, so per-node data such as inferred
+/// types is keyed by id instead. Cloning a node keeps its id.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct NodeId(usize);
+
+impl NodeId {
+    fn fresh() -> Self {
+        static NEXT: AtomicUsize = AtomicUsize::new(0);
+        NodeId(NEXT.fetch_add(1, Ordering::Relaxed))
     }
 }
-#[derive(Debug)]
+
+/// A node together with its source span and [`NodeId`].
+///
+/// Equality compares the node and span only, so two separately parsed copies
+/// of the same source are equal.
+#[derive(Debug, Clone)]
 pub struct Spanned<T> {
     pub node: T,
     pub span: Span,
+    pub id: NodeId,
+}
+
+impl<T: PartialEq> PartialEq for Spanned<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.node == other.node && self.span == other.span
+    }
+}
+
+/// A read-only traversal over the AST.
+///
+/// Every hook has a default implementation that recurses into the node's
+/// children, so an implementor only overrides the hooks it cares about.
+/// [`Visitor::visit_stmt`] and [`Visitor::visit_expr`] dispatch to the
+/// per-variant hooks via [`walk_stmt`] and [`walk_expr`]; an overriding hook
+/// that still wants to descend should call the matching `walk_*` function or
+/// visit the children itself.
+///
+/// ```rust
+/// use zast::{
+///     ast::{Expression, Visitor, walk_program},
+///     lexer::tokens::TokenKind,
+///     parser::ZastParser,
+/// };
+///
+/// struct BinaryCounter(usize);
+///
+/// impl Visitor for BinaryCounter {
+///     fn visit_binary_expression(&mut self, left: &Expression, _: TokenKind, right: &Expression) {
+///         self.0 += 1;
+///         self.visit_expr(left);
+///         self.visit_expr(right);
+///     }
+/// }
+///
+/// let src = "fn f(a: i32): i32 { const b = a * 2 + 1; if b > a { return b; } return -(a - 1); }";
+/// let mut counter = BinaryCounter(0);
+/// walk_program(&mut counter, &ZastParser::from_source(src).unwrap());
+///
+/// assert_eq!(counter.0, 4);
+/// ```
+pub trait Visitor {
+    fn visit_stmt(&mut self, stmt: &Statement) {
+        walk_stmt(self, stmt);
+    }
+
+    fn visit_expr(&mut self, expr: &Expression) {
+        walk_expr(self, expr);
+    }
+
+    fn visit_function_declaration(
+        &mut self,
+        _name: &str,
+        _parameters: &[FunctionParameter],
+        _return_type: &ReturnType,
+        body: &Statement,
+    ) {
+        self.visit_stmt(body);
+    }
+
+    fn visit_block_statement(&mut self, statements: &[Box<Statement>], tail: Option<&Expression>) {
+        for stmt in statements {
+            self.visit_stmt(stmt);
+        }
+        if let Some(tail) = tail {
+            self.visit_expr(tail);
+        }
+    }
+
+    fn visit_expression_statement(&mut self, expression: &Expression) {
+        self.visit_expr(expression);
+    }
+
+    fn visit_variable_declaration(
+        &mut self,
+        _mutable: bool,
+        _identifier: &str,
+        _annotated_type: Option<&AnnotatedType>,
+        value: &Expression,
+    ) {
+        self.visit_expr(value);
+    }
+
+    fn visit_import(&mut self, _path: &str) {}
+
+    fn visit_struct_declaration(&mut self, _name: &str, _fields: &[FunctionParameter]) {}
+
+    fn visit_enum_declaration(&mut self, _name: &str, _variants: &[String]) {}
+
+    fn visit_return(&mut self, value: Option<&Expression>) {
+        if let Some(value) = value {
+            self.visit_expr(value);
+        }
+    }
+
+    fn visit_if(
+        &mut self,
+        condition: &Expression,
+        then_branch: &Statement,
+        else_branch: Option<&Statement>,
+    ) {
+        self.visit_expr(condition);
+        self.visit_stmt(then_branch);
+        if let Some(else_branch) = else_branch {
+            self.visit_stmt(else_branch);
+        }
+    }
+
+    fn visit_while(&mut self, condition: &Expression, body: &Statement) {
+        self.visit_expr(condition);
+        self.visit_stmt(body);
+    }
+
+    fn visit_integer_literal(&mut self, _value: i64, _suffix: Option<&AnnotatedType>) {}
+
+    fn visit_float_literal(&mut self, _value: f64, _suffix: Option<&AnnotatedType>) {}
+
+    fn visit_char_literal(&mut self, _value: char) {}
+
+    fn visit_string_literal(&mut self, _value: &str) {}
+
+    fn visit_identifier(&mut self, _name: &str) {}
+
+    fn visit_address(&mut self, operand: &Expression) {
+        self.visit_expr(operand);
+    }
+
+    fn visit_dereference(&mut self, operand: &Expression) {
+        self.visit_expr(operand);
+    }
+
+    fn visit_negate(&mut self, operand: &Expression) {
+        self.visit_expr(operand);
+    }
+
+    fn visit_binary_expression(
+        &mut self,
+        left: &Expression,
+        _operator: TokenKind,
+        right: &Expression,
+    ) {
+        self.visit_expr(left);
+        self.visit_expr(right);
+    }
+
+    fn visit_index(&mut self, base: &Expression, index: &Expression) {
+        self.visit_expr(base);
+        self.visit_expr(index);
+    }
+
+    fn visit_field_access(&mut self, base: &Expression, _field: &str) {
+        self.visit_expr(base);
+    }
+
+    fn visit_enum_variant(&mut self, _enum_name: &str, _variant: &str) {}
+
+    fn visit_assignment(&mut self, target: &Expression, value: &Expression) {
+        self.visit_expr(target);
+        self.visit_expr(value);
+    }
+
+    fn visit_ternary(
+        &mut self,
+        condition: &Expression,
+        then_expr: &Expression,
+        else_expr: &Expression,
+    ) {
+        self.visit_expr(condition);
+        self.visit_expr(then_expr);
+        self.visit_expr(else_expr);
+    }
+
+    fn visit_call(&mut self, callee: &Expression, arguments: &[Argument]) {
+        self.visit_expr(callee);
+        for argument in arguments {
+            self.visit_expr(&argument.value);
+        }
+    }
+
+    fn visit_cast(&mut self, expr: &Expression, _target: &AnnotatedType) {
+        self.visit_expr(expr);
+    }
+}
+
+/// Visits every top-level statement of `program` in order.
+pub fn walk_program<V: Visitor + ?Sized>(visitor: &mut V, program: &ZastProgram) {
+    for stmt in &program.body {
+        visitor.visit_stmt(stmt);
+    }
+}
+
+/// Dispatches `stmt` to the [`Visitor`] hook for its variant.
+pub fn walk_stmt<V: Visitor + ?Sized>(visitor: &mut V, stmt: &Statement) {
+    match &stmt.node {
+        Stmt::FunctionDeclaration {
+            name,
+            parameters,
+            return_type,
+            body,
+        } => visitor.visit_function_declaration(name, parameters, return_type, body),
+        Stmt::BlockStatement { statements, tail } => {
+            visitor.visit_block_statement(statements, tail.as_deref())
+        }
+        Stmt::Expression { expression } => visitor.visit_expression_statement(expression),
+        Stmt::VariableDeclaration {
+            mutable,
+            identifier,
+            annotated_type,
+            value,
+        } => {
+            visitor.visit_variable_declaration(*mutable, identifier, annotated_type.as_ref(), value)
+        }
+        Stmt::Import { path } => visitor.visit_import(path),
+        Stmt::StructDeclaration { name, fields } => visitor.visit_struct_declaration(name, fields),
+        Stmt::EnumDeclaration { name, variants } => visitor.visit_enum_declaration(name, variants),
+        Stmt::Return { value } => visitor.visit_return(value.as_ref()),
+        Stmt::If {
+            condition,
+            then_branch,
+            else_branch,
+        } => visitor.visit_if(condition, then_branch, else_branch.as_deref()),
+        Stmt::While { condition, body } => visitor.visit_while(condition, body),
+    }
+}
+
+/// Dispatches `expr` to the [`Visitor`] hook for its variant.
+pub fn walk_expr<V: Visitor + ?Sized>(visitor: &mut V, expr: &Expression) {
+    match &expr.node {
+        Expr::IntegerLiteral { value, suffix } => {
+            visitor.visit_integer_literal(*value, suffix.as_ref())
+        }
+        Expr::FloatLiteral { value, suffix } => {
+            visitor.visit_float_literal(*value, suffix.as_ref())
+        }
+        Expr::CharLiteral(value) => visitor.visit_char_literal(*value),
+        Expr::StringLiteral(value) => visitor.visit_string_literal(value),
+        Expr::Identifier(name) => visitor.visit_identifier(name),
+        Expr::Address(operand) => visitor.visit_address(operand),
+        Expr::Dereference(operand) => visitor.visit_dereference(operand),
+        Expr::Negate(operand) => visitor.visit_negate(operand),
+        Expr::BinaryExpression {
+            left,
+            operator,
+            right,
+        } => visitor.visit_binary_expression(left, *operator, right),
+        Expr::Index { base, index } => visitor.visit_index(base, index),
+        Expr::FieldAccess { base, field } => visitor.visit_field_access(base, field),
+        Expr::EnumVariant { enum_name, variant } => visitor.visit_enum_variant(enum_name, variant),
+        Expr::Assignment { target, value } => visitor.visit_assignment(target, value),
+        Expr::Ternary {
+            condition,
+            then_expr,
+            else_expr,
+        } => visitor.visit_ternary(condition, then_expr, else_expr),
+        Expr::Call { callee, arguments } => visitor.visit_call(callee, arguments),
+        Expr::Cast { expr, target } => visitor.visit_cast(expr, target),
+    }
+}
+
+impl ZastProgram {
+    /// Renders the program as an indented tree, one node per line, each
+    /// tagged with its `ln:col` range, e.g. `FunctionDeclaration 'main' @1:1-4:1`.
+    ///
+    /// Compound expressions that fit on one line also show the source text
+    /// their span covers, so span bugs are visible at a glance.
+    ///
+    /// ```rust
+    /// use zast::parser::ZastParser;
+    ///
+    /// let src = "fn main(): void {\n    let x: i32 = 1 + 2;\n}";
+    /// let program = ZastParser::from_source(src).unwrap();
+    ///
+    /// assert_eq!(
+    ///     program.dump(src).lines().collect::<Vec<_>>(),
+    ///     [
+    ///         "FunctionDeclaration 'main' @1:1-3:1",
+    ///         "  BlockStatement @1:17-3:1",
+    ///         "    VariableDeclaration let 'x' @2:5-22",
+    ///         "      BinaryExpression Plus `1 + 2` @2:18-22",
+    ///         "        IntegerLiteral 1 @2:18",
+    ///         "        IntegerLiteral 2 @2:22",
+    ///     ]
+    /// );
+    /// ```
+    pub fn dump(&self, src: &str) -> String {
+        let mut out = String::new();
+        for stmt in &self.body {
+            dump_stmt(stmt, src, 0, &mut out);
+        }
+        out
+    }
+}
+
+fn dump_line(out: &mut String, depth: usize, label: &str, span: Span) {
+    out.push_str(&format!(
+        "{}{} @{}\n",
+        "  ".repeat(depth),
+        label,
+        Span::format_span(span)
+    ));
+}
+
+fn dump_stmt(stmt: &Statement, src: &str, depth: usize, out: &mut String) {
+    match &stmt.node {
+        Stmt::FunctionDeclaration {
+            name,
+            parameters,
+            body,
+            ..
+        } => {
+            dump_line(
+                out,
+                depth,
+                &format!("FunctionDeclaration '{}'", name),
+                stmt.span,
+            );
+            for param in parameters {
+                dump_line(
+                    out,
+                    depth + 1,
+                    &format!("Parameter '{}'", param.name),
+                    param.span,
+                );
+            }
+            dump_stmt(body, src, depth + 1, out);
+        }
+        Stmt::BlockStatement { statements, tail } => {
+            dump_line(out, depth, "BlockStatement", stmt.span);
+            for stmt in statements {
+                dump_stmt(stmt, src, depth + 1, out);
+            }
+            if let Some(tail) = tail {
+                dump_expr(tail, src, depth + 1, out);
+            }
+        }
+        Stmt::Expression { expression } => {
+            dump_line(out, depth, "ExpressionStatement", stmt.span);
+            dump_expr(expression, src, depth + 1, out);
+        }
+        Stmt::VariableDeclaration {
+            mutable,
+            identifier,
+            value,
+            ..
+        } => {
+            let keyword = if *mutable { "let" } else { "const" };
+            let label = format!("VariableDeclaration {} '{}'", keyword, identifier);
+            dump_line(out, depth, &label, stmt.span);
+            dump_expr(value, src, depth + 1, out);
+        }
+        Stmt::Import { path } => dump_line(out, depth, &format!("Import \"{}\"", path), stmt.span),
+        Stmt::StructDeclaration { name, fields } => {
+            dump_line(
+                out,
+                depth,
+                &format!("StructDeclaration '{}'", name),
+                stmt.span,
+            );
+            for field in fields {
+                dump_line(
+                    out,
+                    depth + 1,
+                    &format!("Field '{}'", field.name),
+                    field.span,
+                );
+            }
+        }
+        Stmt::EnumDeclaration { name, variants } => {
+            let label = format!("EnumDeclaration '{}' {{ {} }}", name, variants.join(", "));
+            dump_line(out, depth, &label, stmt.span);
+        }
+        Stmt::Return { value } => {
+            dump_line(out, depth, "Return", stmt.span);
+            if let Some(value) = value {
+                dump_expr(value, src, depth + 1, out);
+            }
+        }
+        Stmt::If {
+            condition,
+            then_branch,
+            else_branch,
+        } => {
+            dump_line(out, depth, "If", stmt.span);
+            dump_expr(condition, src, depth + 1, out);
+            dump_stmt(then_branch, src, depth + 1, out);
+            if let Some(else_branch) = else_branch {
+                dump_stmt(else_branch, src, depth + 1, out);
+            }
+        }
+        Stmt::While { condition, body } => {
+            dump_line(out, depth, "While", stmt.span);
+            dump_expr(condition, src, depth + 1, out);
+            dump_stmt(body, src, depth + 1, out);
+        }
+    }
+}
+
+fn dump_expr(expr: &Expression, src: &str, depth: usize, out: &mut String) {
+    let mut children: Vec<&Expression> = Vec::new();
+    let label = match &expr.node {
+        Expr::IntegerLiteral { value, suffix } => match suffix {
+            Some(suffix) => format!("IntegerLiteral {} : {:?}", value, suffix),
+            None => format!("IntegerLiteral {}", value),
+        },
+        Expr::FloatLiteral { value, suffix } => match suffix {
+            Some(suffix) => format!("FloatLiteral {:?} : {:?}", value, suffix),
+            None => format!("FloatLiteral {:?}", value),
+        },
+        Expr::CharLiteral(value) => format!("CharLiteral {:?}", value),
+        Expr::StringLiteral(value) => format!("StringLiteral {:?}", value),
+        Expr::Identifier(name) => format!("Identifier '{}'", name),
+        Expr::Address(operand) => {
+            children.push(operand);
+            String::from("Address")
+        }
+        Expr::Dereference(operand) => {
+            children.push(operand);
+            String::from("Dereference")
+        }
+        Expr::Negate(operand) => {
+            children.push(operand);
+            String::from("Negate")
+        }
+        Expr::BinaryExpression {
+            left,
+            operator,
+            right,
+        } => {
+            children.extend([left.as_ref(), right.as_ref()]);
+            format!("BinaryExpression {:?}", operator)
+        }
+        Expr::Index { base, index } => {
+            children.extend([base.as_ref(), index.as_ref()]);
+            String::from("Index")
+        }
+        Expr::FieldAccess { base, field } => {
+            children.push(base);
+            format!("FieldAccess '{}'", field)
+        }
+        Expr::EnumVariant { enum_name, variant } => {
+            format!("EnumVariant '{}.{}'", enum_name, variant)
+        }
+        Expr::Assignment { target, value } => {
+            children.extend([target.as_ref(), value.as_ref()]);
+            String::from("Assignment")
+        }
+        Expr::Ternary {
+            condition,
+            then_expr,
+            else_expr,
+        } => {
+            children.extend([condition.as_ref(), then_expr.as_ref(), else_expr.as_ref()]);
+            String::from("Ternary")
+        }
+        Expr::Call { callee, arguments } => {
+            children.push(callee);
+            children.extend(arguments.iter().map(|argument| &argument.value));
+            String::from("Call")
+        }
+        Expr::Cast { expr, target } => {
+            children.push(expr);
+            format!("Cast : {:?}", target)
+        }
+    };
+
+    // leaves already show their value
+    let label = match span_source(expr.span, src) {
+        Some(text) if !children.is_empty() => format!("{} `{}`", label, text),
+        _ => label,
+    };
+    dump_line(out, depth, &label, expr.span);
+
+    for child in children {
+        dump_expr(child, src, depth + 1, out);
+    }
+}
+
+/// Returns the source text covered by a single-line `span`, if it lies
+/// within `src`.
+fn span_source(span: Span, src: &str) -> Option<String> {
+    if span.ln_start != span.ln_end {
+        return None;
+    }
+
+    SourceMap::new(src).span_text(span).map(String::from)
 }
@@ -0,0 +1,360 @@
+use crate::{
+    ast::{Argument, Expr, Expression, FunctionParameter, Statement, Stmt, ZastProgram},
+    lexer::tokens::TokenKind,
+    parser::precedence_table::{Associativity, Precedence},
+};
+
+const INDENT: &str = "    ";
+
+impl ZastProgram {
+    /// Renders the program back into Zast source, one top-level statement
+    /// after another, with four-space indentation and canonical spacing.
+    ///
+    /// Parentheses are only added where precedence requires them, so parsing
+    /// the output yields the same tree, apart from spans. Comments and the
+    /// original layout are not preserved, and compound assignments come back
+    /// desugared, e.g. `x += 1` as `x = x + 1`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use zast::parser::ZastParser;
+    ///
+    /// let src = "fn f(a: i32): i32 {\n    return (a + 1) * 2;\n}\n";
+    /// let program = ZastParser::from_source(src).unwrap();
+    /// assert_eq!(program.to_source(), src);
+    ///
+    /// let reparsed = ZastParser::from_source(&program.to_source()).unwrap();
+    /// assert_eq!(reparsed.to_source(), src);
+    /// ```
+    pub fn to_source(&self) -> String {
+        let mut out = String::new();
+        for stmt in &self.body {
+            print_stmt(stmt, 0, &mut out);
+        }
+        out
+    }
+}
+
+impl Stmt {
+    /// Renders the statement as Zast source, as [`ZastProgram::to_source`] does.
+    pub fn to_source(&self) -> String {
+        let mut out = String::new();
+        print_stmt_node(self, 0, &mut out);
+        out
+    }
+}
+
+impl Expr {
+    /// Renders the expression as Zast source, as [`ZastProgram::to_source`] does.
+    pub fn to_source(&self) -> String {
+        let mut out = String::new();
+        print_expr_node(self, Precedence::Default.into(), &mut out);
+        out
+    }
+}
+
+fn print_stmt(stmt: &Statement, depth: usize, out: &mut String) {
+    print_stmt_node(&stmt.node, depth, out);
+}
+
+/// Writes `stmt` on its own line(s) at `depth`, ending with a newline.
+fn print_stmt_node(stmt: &Stmt, depth: usize, out: &mut String) {
+    out.push_str(&INDENT.repeat(depth));
+    print_stmt_inline(stmt, depth, out);
+    out.push('\n');
+}
+
+/// Writes `stmt` starting at the current position, without the leading
+/// indentation or trailing newline, so `else if` can continue a line.
+fn print_stmt_inline(stmt: &Stmt, depth: usize, out: &mut String) {
+    match stmt {
+        Stmt::FunctionDeclaration {
+            name,
+            parameters,
+            return_type,
+            body,
+        } => {
+            out.push_str(&format!(
+                "fn {}({}): {} ",
+                name,
+                print_params(parameters),
+                return_type
+            ));
+            print_stmt_inline(&body.node, depth, out);
+        }
+        Stmt::BlockStatement { statements, tail } => {
+            if statements.is_empty() && tail.is_none() {
+                out.push_str("{}");
+                return;
+            }
+
+            out.push_str("{\n");
+            for stmt in statements {
+                print_stmt(stmt, depth + 1, out);
+            }
+            if let Some(tail) = tail {
+                out.push_str(&INDENT.repeat(depth + 1));
+                print_expr(tail, Precedence::Default.into(), out);
+                out.push('\n');
+            }
+            out.push_str(&INDENT.repeat(depth));
+            out.push('}');
+        }
+        Stmt::Expression { expression } => {
+            print_expr(expression, Precedence::Default.into(), out);
+            out.push(';');
+        }
+        Stmt::VariableDeclaration {
+            mutable,
+            identifier,
+            annotated_type,
+            value,
+        } => {
+            let keyword = if *mutable { "let" } else { "const" };
+            out.push_str(&format!("{} {}", keyword, identifier));
+            if let Some(annotated_type) = annotated_type {
+                out.push_str(&format!(": {}", annotated_type));
+            }
+            out.push_str(" = ");
+            print_expr(value, Precedence::Default.into(), out);
+            out.push(';');
+        }
+        Stmt::Import { path } => {
+            out.push_str(&format!("import {};", quote(path, '"')));
+        }
+        Stmt::StructDeclaration { name, fields } => {
+            if fields.is_empty() {
+                out.push_str(&format!("struct {} {{}}", name));
+            } else {
+                out.push_str(&format!("struct {} {{ {} }}", name, print_params(fields)));
+            }
+        }
+        Stmt::EnumDeclaration { name, variants } => {
+            if variants.is_empty() {
+                out.push_str(&format!("enum {} {{}}", name));
+            } else {
+                out.push_str(&format!("enum {} {{ {} }}", name, variants.join(", ")));
+            }
+        }
+        Stmt::Return { value } => {
+            out.push_str("return");
+            if let Some(value) = value {
+                out.push(' ');
+                print_expr(value, Precedence::Default.into(), out);
+            }
+            out.push(';');
+        }
+        Stmt::If {
+            condition,
+            then_branch,
+            else_branch,
+        } => {
+            out.push_str("if ");
+            print_expr(condition, Precedence::Default.into(), out);
+            out.push(' ');
+            print_stmt_inline(&then_branch.node, depth, out);
+            if let Some(else_branch) = else_branch {
+                out.push_str(" else ");
+                print_stmt_inline(&else_branch.node, depth, out);
+            }
+        }
+        Stmt::While { condition, body } => {
+            out.push_str("while ");
+            print_expr(condition, Precedence::Default.into(), out);
+            out.push(' ');
+            print_stmt_inline(&body.node, depth, out);
+        }
+    }
+}
+
+fn print_params(params: &[FunctionParameter]) -> String {
+    let params: Vec<String> = params
+        .iter()
+        .map(|param| format!("{}: {}", param.name, param.annotated_type))
+        .collect();
+    params.join(", ")
+}
+
+fn print_expr(expr: &Expression, min: u8, out: &mut String) {
+    print_expr_node(&expr.node, min, out);
+}
+
+/// Writes `expr`, wrapped in parentheses if it binds looser than `min`, the
+/// precedence its position requires to parse back as the same tree.
+fn print_expr_node(expr: &Expr, min: u8, out: &mut String) {
+    let prec = expr_precedence(expr);
+    if prec < min {
+        out.push('(');
+        print_expr_node(expr, Precedence::Default.into(), out);
+        out.push(')');
+        return;
+    }
+
+    match expr {
+        Expr::IntegerLiteral { value, suffix } => {
+            out.push_str(&value.to_string());
+            if let Some(suffix) = suffix {
+                out.push_str(&suffix.to_string());
+            }
+        }
+        Expr::FloatLiteral { value, suffix } => {
+            let value = value.to_string();
+            out.push_str(&value);
+            // `3f32` would lex as an integer
+            if !value.contains('.') {
+                out.push_str(".0");
+            }
+            if let Some(suffix) = suffix {
+                out.push_str(&suffix.to_string());
+            }
+        }
+        Expr::CharLiteral(value) => out.push_str(&quote(&value.to_string(), '\'')),
+        Expr::StringLiteral(value) => out.push_str(&quote(value, '"')),
+        Expr::Identifier(name) => out.push_str(name),
+        Expr::Address(operand) => print_unary("&", operand, out),
+        Expr::Dereference(operand) => print_unary("*", operand, out),
+        Expr::Negate(operand) => print_unary("-", operand, out),
+        Expr::BinaryExpression {
+            left,
+            operator,
+            right,
+        } => {
+            let (left_min, right_min) = match Precedence::associativity(*operator) {
+                Associativity::Left => (prec, prec + 1),
+                Associativity::Right => (prec + 1, prec),
+            };
+
+            print_expr(left, left_min, out);
+            out.push_str(&format!(" {} ", operator_symbol(*operator)));
+            print_expr(right, right_min, out);
+        }
+        Expr::Index { base, index } => {
+            print_expr(base, Precedence::Call.into(), out);
+            out.push('[');
+            print_expr(index, Precedence::Default.into(), out);
+            out.push(']');
+        }
+        Expr::FieldAccess { base, field } => {
+            print_expr(base, Precedence::Call.into(), out);
+            out.push('.');
+            out.push_str(field);
+        }
+        Expr::EnumVariant { enum_name, variant } => {
+            out.push_str(&format!("{}.{}", enum_name, variant));
+        }
+        Expr::Assignment { target, value } => {
+            print_expr(target, prec + 1, out);
+            out.push_str(" = ");
+            print_expr(value, prec, out);
+        }
+        Expr::Ternary {
+            condition,
+            then_expr,
+            else_expr,
+        } => {
+            print_expr(condition, prec + 1, out);
+            out.push_str(" ? ");
+            print_expr(then_expr, Precedence::Default.into(), out);
+            out.push_str(" : ");
+            print_expr(else_expr, prec, out);
+        }
+        Expr::Call { callee, arguments } => {
+            print_expr(callee, Precedence::Call.into(), out);
+            out.push('(');
+            for (i, argument) in arguments.iter().enumerate() {
+                if i > 0 {
+                    out.push_str(", ");
+                }
+                print_argument(argument, out);
+            }
+            out.push(')');
+        }
+        Expr::Cast { expr, target } => {
+            print_expr(expr, prec, out);
+            out.push_str(&format!(" as {}", target));
+        }
+    }
+}
+
+fn print_unary(op: &str, operand: &Expression, out: &mut String) {
+    out.push_str(op);
+    // parenthesize nested prefix operators so `&(&x)` doesn't lex as `&&x`
+    print_expr(operand, u8::from(Precedence::Unary) + 1, out);
+}
+
+fn print_argument(argument: &Argument, out: &mut String) {
+    if let Some(name) = &argument.name {
+        out.push_str(name);
+        out.push_str(": ");
+    }
+    print_expr(&argument.value, Precedence::Default.into(), out);
+}
+
+/// Returns how tightly `expr` binds, as the [`Precedence`] of its operator.
+/// Literals, names, and postfix forms bind tightest.
+fn expr_precedence(expr: &Expr) -> u8 {
+    let precedence = match expr {
+        Expr::IntegerLiteral { .. }
+        | Expr::FloatLiteral { .. }
+        | Expr::CharLiteral(_)
+        | Expr::StringLiteral(_)
+        | Expr::Identifier(_) => Precedence::Grouping,
+        Expr::Call { .. }
+        | Expr::Index { .. }
+        | Expr::FieldAccess { .. }
+        | Expr::EnumVariant { .. } => Precedence::Call,
+        Expr::Address(_) | Expr::Dereference(_) | Expr::Negate(_) => Precedence::Unary,
+        Expr::Cast { .. } => Precedence::Cast,
+        Expr::BinaryExpression { operator, .. } => {
+            Precedence::get_precedence(*operator).unwrap_or(Precedence::Default)
+        }
+        Expr::Ternary { .. } => Precedence::Ternary,
+        Expr::Assignment { .. } => Precedence::Assignment,
+    };
+    precedence.into()
+}
+
+fn operator_symbol(operator: TokenKind) -> &'static str {
+    match operator {
+        TokenKind::Plus => "+",
+        TokenKind::Minus => "-",
+        TokenKind::Multiply => "*",
+        TokenKind::Divide => "/",
+        TokenKind::DoubleStar => "**",
+        TokenKind::Caret => "^",
+        TokenKind::Equal => "==",
+        TokenKind::NotEqual => "!=",
+        TokenKind::Less => "<",
+        TokenKind::LessEqual => "<=",
+        TokenKind::Greater => ">",
+        TokenKind::GreaterEqual => ">=",
+        TokenKind::LogicalAnd => "&&",
+        TokenKind::LogicalOr => "||",
+        TokenKind::Ampersand => "&",
+        TokenKind::Pipe => "|",
+        TokenKind::Shl => "<<",
+        TokenKind::Shr => ">>",
+        _ => "?",
+    }
+}
+
+/// Wraps `text` in `delimiter`, escaping what the lexer decodes.
+fn quote(text: &str, delimiter: char) -> String {
+    let mut quoted = String::from(delimiter);
+    for c in text.chars() {
+        match c {
+            '\n' => quoted.push_str("\\n"),
+            '\t' => quoted.push_str("\\t"),
+            '\0' => quoted.push_str("\\0"),
+            '\\' => quoted.push_str("\\\\"),
+            c if c == delimiter => {
+                quoted.push('\\');
+                quoted.push(c);
+            }
+            c => quoted.push(c),
+        }
+    }
+    quoted.push(delimiter);
+    quoted
+}
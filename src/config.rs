@@ -0,0 +1,126 @@
+use crate::types::ValueType;
+
+/// Configuration shared by every stage of the Zast pipeline.
+///
+/// Built with chained setters starting from [`ZastConfig::new`], which uses
+/// the defaults documented on each field, and passed to the `with_config`
+/// constructors of [`ZastLexer`], [`ZastSemanticAnalyzer`], and [`ZastIREmitter`].
+///
+/// [`ZastLexer`]: crate::lexer::ZastLexer
+/// [`ZastSemanticAnalyzer`]: crate::sema::ZastSemanticAnalyzer
+/// [`ZastIREmitter`]: crate::zast_ir::ZastIREmitter
+///
+/// # Example
+///
+/// ```rust
+/// use zast::config::ZastConfig;
+///
+/// let config = ZastConfig::new().tab_width(4).allow_int_widening(true);
+/// ```
+///
+/// Every stage of a [`ZastPipeline`](crate::pipeline::ZastPipeline) honours
+/// the same configuration:
+///
+/// ```rust
+/// use zast::{config::ZastConfig, pipeline::ZastPipeline};
+///
+/// let src = "fn f(): void {\n\tconst x: bool = 1;\n}";
+/// let column = |config: ZastConfig| {
+///     let errors = ZastPipeline::with_config(config).compile(src).errors;
+///     errors.errors()[0].get_span().col_start
+/// };
+/// assert_eq!(column(ZastConfig::new()), 18);
+/// assert_eq!(column(ZastConfig::new().tab_width(4)), 21);
+///
+/// let src = "fn main(): void { const unused = 1; }";
+/// assert!(ZastPipeline::new().compile(src).is_success());
+/// let strict = ZastConfig::new().deny_warnings(true);
+/// assert!(!ZastPipeline::with_config(strict).compile(src).is_success());
+/// ```
+#[derive(Debug, Clone)]
+pub struct ZastConfig {
+    /// Number of columns a tab advances in reported spans. Defaults to `1`,
+    /// so columns count characters.
+    pub tab_width: usize,
+
+    /// Type given to integer literals that have no other type to adopt.
+    /// Defaults to `i32`.
+    pub default_int_type: ValueType,
+
+    /// Whether an integer may implicitly widen to a larger integer of the same
+    /// signedness. Defaults to `false`.
+    pub allow_int_widening: bool,
+
+    /// Whether warnings fail compilation like errors do. Defaults to `false`.
+    pub deny_warnings: bool,
+
+    /// Whether emitted integer arithmetic is checked for overflow. Constant
+    /// folding then reports an overflowing result instead of wrapping it.
+    /// Defaults to `false`.
+    pub checked_arithmetic: bool,
+
+    /// Width in bits of pointers on the compilation target. Defaults to `64`.
+    pub target_pointer_width: u16,
+
+    /// Maximum number of errors collected per stage, or `None` for no limit.
+    /// Defaults to `None`.
+    pub max_errors: Option<usize>,
+}
+
+impl ZastConfig {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn tab_width(mut self, tab_width: usize) -> Self {
+        self.tab_width = tab_width;
+        self
+    }
+
+    pub fn default_int_type(mut self, default_int_type: ValueType) -> Self {
+        self.default_int_type = default_int_type;
+        self
+    }
+
+    pub fn allow_int_widening(mut self, allow_int_widening: bool) -> Self {
+        self.allow_int_widening = allow_int_widening;
+        self
+    }
+
+    pub fn deny_warnings(mut self, deny_warnings: bool) -> Self {
+        self.deny_warnings = deny_warnings;
+        self
+    }
+
+    pub fn checked_arithmetic(mut self, checked_arithmetic: bool) -> Self {
+        self.checked_arithmetic = checked_arithmetic;
+        self
+    }
+
+    pub fn target_pointer_width(mut self, target_pointer_width: u16) -> Self {
+        self.target_pointer_width = target_pointer_width;
+        self
+    }
+
+    pub fn max_errors(mut self, max_errors: usize) -> Self {
+        self.max_errors = Some(max_errors);
+        self
+    }
+}
+
+impl Default for ZastConfig {
+    fn default() -> Self {
+        Self {
+            tab_width: 1,
+            default_int_type: ValueType::Integer {
+                bits: 32,
+                unsigned: false,
+            },
+            allow_int_widening: false,
+            deny_warnings: false,
+            checked_arithmetic: false,
+            target_pointer_width: 64,
+            max_errors: None,
+        }
+    }
+}
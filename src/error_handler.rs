@@ -1,5 +1,12 @@
-use crate::{error_handler::zast_errors::ZastError, lexer::tokens::Span};
+use crate::{
+    config::ZastConfig,
+    error_handler::{error_severity::Severity, zast_errors::ZastError},
+    lexer::tokens::Span,
+    source_map::SourceMap,
+};
 
+pub mod error_codes;
+pub mod error_severity;
 pub mod error_span;
 pub mod errors_messages;
 pub mod zast_errors;
@@ -7,33 +14,393 @@ pub mod zast_errors;
 #[derive(Default, Debug)]
 pub struct ZastErrorCollector {
     errors: Vec<ZastError>,
+    limit: Option<usize>,
+    suppressed: usize,
+    // keeps has_errors() truthful when only warnings made it under the limit
+    suppressed_error: bool,
+    truncated: bool,
+    deny_warnings: bool,
 }
 
 impl ZastErrorCollector {
     pub fn new() -> Self {
-        Self { errors: Vec::new() }
+        Self {
+            errors: Vec::new(),
+            limit: None,
+            suppressed: 0,
+            suppressed_error: false,
+            truncated: false,
+            deny_warnings: false,
+        }
+    }
+
+    /// Creates a collector honoring the diagnostic settings of `config`: its
+    /// [`max_errors`](ZastConfig::max_errors) limit and whether it
+    /// [denies warnings](ZastConfig::deny_warnings).
+    pub fn from_config(config: &ZastConfig) -> Self {
+        let mut collector = config.max_errors.map_or_else(Self::new, Self::with_limit);
+        collector.set_deny_warnings(config.deny_warnings);
+        collector
+    }
+
+    /// Creates a collector that keeps at most `limit` diagnostics. Any further
+    /// diagnostics are counted but dropped, see [`ZastErrorCollector::suppressed`].
+    ///
+    /// ```rust
+    /// use zast::{
+    ///     error_handler::{ZastErrorCollector, zast_errors::ZastError},
+    ///     lexer::tokens::Span,
+    /// };
+    ///
+    /// let mut errors = ZastErrorCollector::with_limit(2);
+    /// for ln in 1..=5 {
+    ///     let span = Span { ln_start: ln, ln_end: ln, col_start: 1, col_end: 1 };
+    ///     errors.add_error(ZastError::UnterminatedStringLiteral { span });
+    /// }
+    ///
+    /// assert_eq!(errors.errors().len(), 2);
+    /// assert_eq!(errors.suppressed(), 3);
+    /// assert_eq!(errors.suppression_notice().unwrap(), "... 3 more errors suppressed");
+    /// ```
+    ///
+    /// Through [`ZastConfig::max_errors`], the parser stops once the limit is
+    /// reached instead of producing errors only to drop them:
+    ///
+    /// ```rust
+    /// use zast::{config::ZastConfig, pipeline::ZastPipeline};
+    ///
+    /// let src = ") ) ) ) ) ) ) ) ) )";
+    /// let pipeline = ZastPipeline::with_config(ZastConfig::new().max_errors(3));
+    /// let rendered = pipeline.compile(src).errors.render_all(src);
+    ///
+    /// assert_eq!(rendered.len(), 4);
+    /// assert_eq!(rendered[3], "... more errors suppressed, stopped after 3");
+    /// ```
+    pub fn with_limit(limit: usize) -> Self {
+        Self {
+            limit: Some(limit),
+            ..Self::new()
+        }
+    }
+
+    /// Returns `true` once the collector holds as many diagnostics as its
+    /// limit allows. Always `false` for an unlimited collector.
+    pub fn is_full(&self) -> bool {
+        self.limit.is_some_and(|limit| self.errors.len() >= limit)
+    }
+
+    /// Makes warnings count as errors in [`ZastErrorCollector::has_errors`],
+    /// so a phase that only produced warnings fails.
+    pub fn set_deny_warnings(&mut self, deny_warnings: bool) {
+        self.deny_warnings = deny_warnings;
+    }
+
+    /// Returns the number of diagnostics dropped because the limit was reached.
+    pub fn suppressed(&self) -> usize {
+        self.suppressed
+    }
+
+    /// Records that the producing stage stopped early because the collector
+    /// was full, so an unknown number of further errors was never produced.
+    pub fn mark_truncated(&mut self) {
+        self.truncated = true;
     }
 
-    pub fn report_all_errors(&self) {
-        for i in 0..self.errors.len() {
-            self.report_error(i);
+    /// Returns the line printed after the diagnostics when some were dropped
+    /// or never produced because of the limit, e.g.
+    /// `... 3 more errors suppressed`.
+    pub fn suppression_notice(&self) -> Option<String> {
+        if self.truncated {
+            Some(format!(
+                "... more errors suppressed, stopped after {}",
+                self.errors.len()
+            ))
+        } else if self.suppressed > 0 {
+            Some(format!("... {} more errors suppressed", self.suppressed))
+        } else {
+            None
         }
     }
 
-    pub fn report_error(&self, error_idx: usize) {
+    /// Returns the collected diagnostics in the order they were reported.
+    pub fn errors(&self) -> &[ZastError] {
+        &self.errors
+    }
+
+    /// Consumes the collector, returning its diagnostics.
+    pub fn into_errors(self) -> Vec<ZastError> {
+        self.errors
+    }
+
+    /// Renders every diagnostic as [`ZastErrorCollector::render_error`] does,
+    /// followed by the [suppression notice](ZastErrorCollector::suppression_notice)
+    /// if there is one. This is what [`ZastErrorCollector::report_all_errors`]
+    /// prints.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use zast::pipeline::ZastPipeline;
+    ///
+    /// let src = "const x: i32 = y;";
+    /// let rendered = ZastPipeline::new().compile(src).errors.render_all(src);
+    ///
+    /// assert_eq!(rendered.len(), 1);
+    /// assert!(rendered[0].starts_with("Error at: "));
+    /// assert!(rendered[0].contains("Use of undeclared variable 'y'"));
+    /// ```
+    pub fn render_all(&self, src: &str) -> Vec<String> {
+        (0..self.errors.len())
+            .map(|i| self.render_error(i, src))
+            .chain(self.suppression_notice())
+            .collect()
+    }
+
+    pub fn report_all_errors(&self, src: &str) {
+        for rendered in self.render_all(src) {
+            eprintln!("{}", rendered);
+        }
+    }
+
+    pub fn report_error(&self, error_idx: usize, src: &str) {
+        eprintln!("{}", self.render_error(error_idx, src));
+    }
+
+    /// Renders a single diagnostic as its header line followed by the
+    /// offending source line(s), with a `^` underline beneath its span.
+    ///
+    /// The header starts with `Error at:` or `Warning at:` depending on the
+    /// diagnostic's [`Severity`].
+    ///
+    /// ```rust
+    /// use zast::pipeline::ZastPipeline;
+    ///
+    /// let src = "const x: i32 = yy;";
+    /// let errors = ZastPipeline::new().compile(src).errors;
+    /// assert_eq!(
+    ///     errors.render_error(0, src).lines().collect::<Vec<_>>(),
+    ///     [
+    ///         "Error at: 1:16-17 | Use of undeclared variable 'yy'",
+    ///         "1 | const x: i32 = yy;",
+    ///         "  |                ^^",
+    ///     ]
+    /// );
+    ///
+    /// // multi-line spans underline the rest of their first line
+    /// let src = "fn f(): i32 {\n    let a = 1;\n}";
+    /// let errors = ZastPipeline::new().compile(src).errors;
+    /// assert_eq!(
+    ///     errors.render_error(1, src).lines().collect::<Vec<_>>(),
+    ///     [
+    ///         "Error at: 1:1-3:1 | Function 'f' does not return a value on every path",
+    ///         "1 | fn f(): i32 {",
+    ///         "  | ^^^^^^^^^^^^^",
+    ///         "2 |     let a = 1;",
+    ///         "3 | }",
+    ///     ]
+    /// );
+    /// ```
+    pub fn render_error(&self, error_idx: usize, src: &str) -> String {
         let error = &self.errors[error_idx];
-        eprintln!(
-            "Error at: {} | {}",
-            Span::format_span(error.get_span()),
-            error.get_error_msg()
-        );
+        let span = error.get_span();
+        let prefix = match error.get_severity() {
+            Severity::Error => "Error",
+            Severity::Warning => "Warning",
+        };
+
+        format!(
+            "{} at: {} | {}\n{}",
+            prefix,
+            Span::format_span(span),
+            error.get_error_msg(),
+            Self::render_snippet(span, src)
+        )
     }
 
+    /// Adds an error to the collector.
+    ///
+    /// An error identical to one already collected (same variant, span, and
+    /// payload) is dropped, so cascading failures during parser recovery are
+    /// reported once. Distinct errors that merely share a span are all kept.
+    ///
+    /// Once the collector [is full](ZastErrorCollector::is_full), new errors
+    /// are only counted as suppressed.
+    ///
+    /// ```rust
+    /// use zast::{
+    ///     error_handler::{ZastErrorCollector, zast_errors::ZastError},
+    ///     lexer::tokens::{Span, TokenKind},
+    /// };
+    ///
+    /// let span = Span { col_start: 5, col_end: 5, ln_start: 1, ln_end: 1 };
+    /// let unexpected = || ZastError::UnexpectedToken { span, token_kind: TokenKind::Semicolon };
+    ///
+    /// let mut errors = ZastErrorCollector::new();
+    /// errors.add_error(unexpected());
+    /// errors.add_error(unexpected());
+    /// errors.add_error(ZastError::UnterminatedStringLiteral { span });
+    ///
+    /// assert_eq!(errors.errors().len(), 2);
+    /// ```
     pub fn add_error(&mut self, zast_error: ZastError) {
-        self.errors.push(zast_error);
+        if self.errors.contains(&zast_error) {
+            return;
+        }
+
+        if self.is_full() {
+            self.suppressed += 1;
+            self.suppressed_error |= zast_error.get_severity() == Severity::Error;
+        } else {
+            self.errors.push(zast_error);
+        }
     }
 
+    /// Appends the diagnostics of `other`, e.g. to report every phase's errors
+    /// together. Each one goes through [`ZastErrorCollector::add_error`], so
+    /// duplicates are dropped and this collector's limit applies; anything
+    /// `other` had already suppressed stays counted as suppressed.
+    ///
+    /// ```rust
+    /// use zast::{
+    ///     error_handler::{ZastErrorCollector, zast_errors::ZastError},
+    ///     lexer::tokens::Span,
+    /// };
+    ///
+    /// let span = |ln| Span { ln_start: ln, ln_end: ln, col_start: 1, col_end: 1 };
+    ///
+    /// let mut lexer_errors = ZastErrorCollector::new();
+    /// lexer_errors.add_error(ZastError::UnterminatedStringLiteral { span: span(1) });
+    ///
+    /// let mut parser_errors = ZastErrorCollector::new();
+    /// parser_errors.add_error(ZastError::UnterminatedStringLiteral { span: span(1) });
+    /// parser_errors.add_error(ZastError::UnterminatedBlockComment { span: span(2) });
+    ///
+    /// let mut all = ZastErrorCollector::new();
+    /// assert!(!all.has_errors());
+    /// all.extend(lexer_errors);
+    /// all.extend(parser_errors);
+    ///
+    /// // the duplicate of the lexer's error is dropped
+    /// assert_eq!(all.errors().len(), 2);
+    /// assert!(all.has_errors());
+    /// ```
+    pub fn extend(&mut self, other: ZastErrorCollector) {
+        for error in other.errors {
+            self.add_error(error);
+        }
+
+        self.suppressed += other.suppressed;
+        self.suppressed_error |= other.suppressed_error;
+        self.truncated |= other.truncated;
+    }
+
+    /// Returns `true` if any collected diagnostic has [`Severity::Error`].
+    /// Warnings alone never fail a phase, unless the collector
+    /// [denies warnings](ZastErrorCollector::set_deny_warnings).
     pub fn has_errors(&self) -> bool {
-        !self.errors.is_empty()
+        if self.deny_warnings && (self.suppressed > 0 || self.has_warnings()) {
+            return true;
+        }
+
+        self.suppressed_error
+            || self
+                .errors
+                .iter()
+                .any(|error| error.get_severity() == Severity::Error)
+    }
+
+    /// Returns `true` if any collected diagnostic has [`Severity::Warning`].
+    pub fn has_warnings(&self) -> bool {
+        self.errors
+            .iter()
+            .any(|error| error.get_severity() == Severity::Warning)
+    }
+
+    /// Serializes all collected errors as a JSON array for editor and CI
+    /// integrations. Each entry carries the error `code`, its `severity`
+    /// (`"error"` or `"warning"`), its `message`, and the span bounds as
+    /// `line_start`, `line_end`, `col_start`, and `col_end`.
+    pub fn to_json(&self) -> String {
+        let entries: Vec<String> = self
+            .errors
+            .iter()
+            .map(|error| {
+                let span = error.get_span();
+                format!(
+                    r#"{{"code":"{}","severity":"{}","message":"{}","line_start":{},"line_end":{},"col_start":{},"col_end":{}}}"#,
+                    error.get_error_code(),
+                    match error.get_severity() {
+                        Severity::Error => "error",
+                        Severity::Warning => "warning",
+                    },
+                    Self::escape_json(&error.get_error_msg()),
+                    span.ln_start,
+                    span.ln_end,
+                    span.col_start,
+                    span.col_end
+                )
+            })
+            .collect();
+
+        format!("[{}]", entries.join(","))
+    }
+
+    /// Escapes `s` for embedding inside a JSON string literal.
+    fn escape_json(s: &str) -> String {
+        let mut escaped = String::with_capacity(s.len());
+
+        for c in s.chars() {
+            match c {
+                '"' => escaped.push_str("\\\""),
+                '\\' => escaped.push_str("\\\\"),
+                '\n' => escaped.push_str("\\n"),
+                '\r' => escaped.push_str("\\r"),
+                '\t' => escaped.push_str("\\t"),
+                c if (c as u32) < 0x20 => escaped.push_str(&format!("\\u{:04x}", c as u32)),
+                c => escaped.push(c),
+            }
+        }
+
+        escaped
+    }
+
+    /// Renders the source lines covered by `span`, each prefixed with its line
+    /// number. Single-line spans are underlined from `col_start` to `col_end`
+    /// inclusive; multi-line spans are underlined from `col_start` to the end
+    /// of the first line.
+    fn render_snippet(span: Span, src: &str) -> String {
+        let source_map = SourceMap::new(src);
+        if span.ln_start == 0 || span.ln_start > source_map.line_count() {
+            return String::new();
+        }
+
+        let ln_end = span.ln_end.clamp(span.ln_start, source_map.line_count());
+        let gutter_width = ln_end.to_string().len();
+        let mut snippet = String::new();
+
+        for ln in span.ln_start..=ln_end {
+            let line = source_map.line(ln).unwrap_or_default();
+            snippet.push_str(&format!("{:>w$} | {}\n", ln, line, w = gutter_width));
+
+            if ln == span.ln_start {
+                let line_len = line.chars().count();
+                let col_start = span.col_start.max(1);
+                let col_end = if span.ln_start == span.ln_end {
+                    span.col_end.max(col_start)
+                } else {
+                    line_len.max(col_start)
+                };
+
+                snippet.push_str(&format!(
+                    "{:>w$} | {}{}\n",
+                    "",
+                    " ".repeat(col_start - 1),
+                    "^".repeat(col_end - col_start + 1),
+                    w = gutter_width
+                ));
+            }
+        }
+
+        snippet
     }
 }
@@ -0,0 +1,58 @@
+use crate::error_handler::zast_errors::ZastError;
+
+impl ZastError {
+    /// Returns the stable diagnostic code for this error, e.g. `E0005`.
+    ///
+    /// Codes are never reused or renumbered; new variants take the next free code.
+    pub fn get_error_code(&self) -> &'static str {
+        match self {
+            Self::UnexpectedToken { .. } => "E0001",
+            Self::ExpectedToken { .. } => "E0002",
+            Self::IllegalToken { .. } => "E0003",
+            Self::UnterminatedStringLiteral { .. } => "E0004",
+            Self::VariableRedeclaration { .. } => "E0005",
+            Self::FunctionRedeclaration { .. } => "E0006",
+            Self::UndeclaredVariable { .. } => "E0007",
+            Self::TypeMismatch { .. } => "E0008",
+            Self::InvalidDereference { .. } => "E0009",
+            Self::UnresolvedImport { .. } => "E0010",
+            Self::InvalidImport { .. } => "E0011",
+            Self::AssignedButNeverRead { .. } => "E0012",
+            Self::NotIndexable { .. } => "E0013",
+            Self::NonIntegerIndex { .. } => "E0014",
+            Self::UnknownType { .. } => "E0015",
+            Self::TypeRedeclaration { .. } => "E0016",
+            Self::UnknownField { .. } => "E0017",
+            Self::InvalidCharLiteral { .. } => "E0018",
+            Self::AssignToImmutable { .. } => "E0019",
+            Self::InvalidAssignmentTarget { .. } => "E0020",
+            Self::IntegerOutOfRange { .. } => "E0021",
+            Self::InvalidNegation { .. } => "E0022",
+            Self::ReturnOutsideFunction { .. } => "E0023",
+            Self::BinaryTypeMismatch { .. } => "E0024",
+            Self::UnusedVariable { .. } => "E0025",
+            Self::UnreachableCode { .. } => "E0026",
+            Self::DivisionByZero { .. } => "E0027",
+            Self::NotCallable { .. } => "E0028",
+            Self::ArgumentCountMismatch { .. } => "E0029",
+            Self::UnclosedDelimiter { .. } => "E0030",
+            Self::UnterminatedBlockComment { .. } => "E0031",
+            Self::NonConstantArrayLength { .. } => "E0032",
+            Self::UnknownArgumentName { .. } => "E0033",
+            Self::DuplicateArgument { .. } => "E0034",
+            Self::PositionalAfterNamed { .. } => "E0035",
+            Self::MissingReturn { .. } => "E0036",
+            Self::InvalidCast { .. } => "E0037",
+            Self::DuplicateParameter { .. } => "E0038",
+            Self::InvalidNumericLiteral { .. } => "E0039",
+            Self::IntegerLiteralTooLarge { .. } => "E0040",
+            Self::UnknownEnumVariant { .. } => "E0041",
+            Self::NestedFunction { .. } => "E0042",
+            Self::ImportConflict { .. } => "E0043",
+            Self::ArithmeticOverflow { .. } => "E0044",
+            Self::UnsupportedInIR { .. } => "E0045",
+            Self::DuplicateVariant { .. } => "E0046",
+            Self::DuplicateField { .. } => "E0047",
+        }
+    }
+}
@@ -0,0 +1,30 @@
+use crate::error_handler::zast_errors::ZastError;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Severity {
+    Error,
+    Warning,
+}
+
+impl ZastError {
+    /// Returns whether this diagnostic fails compilation or is only a warning.
+    ///
+    /// ```rust
+    /// use zast::{error_handler::error_severity::Severity, pipeline::ZastPipeline};
+    ///
+    /// let src = "fn main(): void { const unused = 1; }";
+    /// let compilation = ZastPipeline::new().compile(src);
+    ///
+    /// assert_eq!(compilation.errors.errors()[0].get_severity(), Severity::Warning);
+    /// assert!(compilation.errors.render_all(src)[0].starts_with("Warning at: "));
+    /// assert!(compilation.into_result().is_ok());
+    /// ```
+    pub fn get_severity(&self) -> Severity {
+        match self {
+            Self::UnusedVariable { .. }
+            | Self::AssignedButNeverRead { .. }
+            | Self::UnreachableCode { .. } => Severity::Warning,
+            _ => Severity::Error,
+        }
+    }
+}
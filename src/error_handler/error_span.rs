@@ -8,6 +8,48 @@ impl ZastError {
             Self::FunctionRedeclaration { span, .. } => *span,
             Self::ExpectedToken { span, .. } => *span,
             Self::IllegalToken { span, .. } => *span,
+            Self::UnterminatedStringLiteral { span } => *span,
+            Self::UnterminatedBlockComment { span } => *span,
+            Self::UndeclaredVariable { span, .. } => *span,
+            Self::TypeMismatch { span, .. } => *span,
+            Self::InvalidDereference { span, .. } => *span,
+            Self::AssignedButNeverRead { span, .. } => *span,
+            Self::UnresolvedImport { span, .. } => *span,
+            Self::InvalidImport { span, .. } => *span,
+            Self::NotIndexable { span, .. } => *span,
+            Self::NonIntegerIndex { span, .. } => *span,
+            Self::UnknownType { span, .. } => *span,
+            Self::TypeRedeclaration { span, .. } => *span,
+            Self::UnknownField { span, .. } => *span,
+            Self::InvalidCharLiteral { span } => *span,
+            Self::AssignToImmutable { span, .. } => *span,
+            Self::InvalidAssignmentTarget { span } => *span,
+            Self::IntegerOutOfRange { span, .. } => *span,
+            Self::InvalidNegation { span, .. } => *span,
+            Self::ReturnOutsideFunction { span } => *span,
+            Self::BinaryTypeMismatch { span, .. } => *span,
+            Self::UnusedVariable { span, .. } => *span,
+            Self::UnreachableCode { span } => *span,
+            Self::DivisionByZero { span } => *span,
+            Self::NotCallable { span, .. } => *span,
+            Self::ArgumentCountMismatch { span, .. } => *span,
+            Self::UnclosedDelimiter { span, .. } => *span,
+            Self::NonConstantArrayLength { span } => *span,
+            Self::UnknownArgumentName { span, .. } => *span,
+            Self::DuplicateArgument { span, .. } => *span,
+            Self::PositionalAfterNamed { span } => *span,
+            Self::MissingReturn { span, .. } => *span,
+            Self::InvalidCast { span, .. } => *span,
+            Self::DuplicateParameter { span, .. } => *span,
+            Self::InvalidNumericLiteral { span, .. } => *span,
+            Self::IntegerLiteralTooLarge { span, .. } => *span,
+            Self::UnknownEnumVariant { span, .. } => *span,
+            Self::NestedFunction { span, .. } => *span,
+            Self::ImportConflict { span, .. } => *span,
+            Self::ArithmeticOverflow { span, .. } => *span,
+            Self::UnsupportedInIR { span, .. } => *span,
+            Self::DuplicateVariant { span, .. } => *span,
+            Self::DuplicateField { span, .. } => *span,
         }
     }
 }
@@ -1,4 +1,7 @@
-use crate::{ast::Spanned, error_handler::zast_errors::ZastError, lexer::tokens::Span};
+use crate::{
+    error_handler::zast_errors::ZastError,
+    lexer::tokens::{Span, TokenKind},
+};
 
 impl ZastError {
     pub fn get_error_msg(&self) -> String {
@@ -30,6 +33,13 @@ impl ZastError {
             Self::IllegalToken { token_lexeme, .. } => {
                 format!("Illegal token found '{}'", token_lexeme)
             }
+            Self::UnterminatedStringLiteral { .. } => {
+                String::from("Unterminated string literal, expected closing '\"'")
+            }
+
+            Self::UnterminatedBlockComment { .. } => {
+                String::from("Unterminated block comment, expected closing '*/'")
+            }
 
             Self::VariableRedeclaration {
                 variable_name,
@@ -53,6 +63,178 @@ impl ZastError {
                     Span::format_span(*original_span)
                 )
             }
+            Self::UndeclaredVariable { variable_name, .. } => {
+                format!("Use of undeclared variable '{}'", variable_name)
+            }
+            Self::TypeMismatch {
+                expected, found, ..
+            } => {
+                format!(
+                    "Mismatched types, expected '{}', found '{}'",
+                    expected, found
+                )
+            }
+            Self::InvalidDereference { found, .. } => {
+                format!("Cannot dereference a value of type '{}'", found)
+            }
+            Self::AssignedButNeverRead { variable_name, .. } => {
+                format!(
+                    "Variable '{}' is assigned but its value is never read",
+                    variable_name
+                )
+            }
+            Self::UnresolvedImport { path, .. } => {
+                format!("Could not resolve import '{}'", path)
+            }
+            Self::InvalidImport { path, .. } => {
+                format!("Imported module '{}' contains errors", path)
+            }
+            Self::NotIndexable { found, .. } => {
+                format!("Cannot index into a value of type '{}'", found)
+            }
+            Self::NonIntegerIndex { found, .. } => {
+                format!("Index must be an integer, found '{}'", found)
+            }
+            Self::UnknownType { type_name, .. } => {
+                format!("Cannot find type '{}' in this scope", type_name)
+            }
+            Self::TypeRedeclaration { type_name, .. } => {
+                format!("Type '{}' is already defined", type_name)
+            }
+            Self::UnknownField {
+                found, field_name, ..
+            } => {
+                format!("No field '{}' on type '{}'", field_name, found)
+            }
+            Self::InvalidCharLiteral { .. } => String::from(
+                "Invalid char literal, expected exactly one character between single quotes",
+            ),
+            Self::AssignToImmutable { variable_name, .. } => {
+                format!("Cannot assign to const binding '{}'", variable_name)
+            }
+            Self::InvalidAssignmentTarget { .. } => {
+                String::from("Invalid left-hand side of assignment")
+            }
+            Self::IntegerOutOfRange { value, ty, .. } => {
+                format!("Literal '{}' out of range for type '{}'", value, ty)
+            }
+            Self::InvalidNegation { found, .. } => {
+                format!("Cannot negate a value of type '{}'", found)
+            }
+            Self::ReturnOutsideFunction { .. } => {
+                String::from("Cannot return outside of a function body")
+            }
+            Self::BinaryTypeMismatch {
+                op, left, right, ..
+            } => {
+                format!(
+                    "Cannot apply '{:?}' to operands of type '{}' and '{}'",
+                    op, left, right
+                )
+            }
+            Self::UnusedVariable { variable_name, .. } => {
+                format!("Unused variable '{}'", variable_name)
+            }
+            Self::UnreachableCode { .. } => String::from("Unreachable code after return"),
+            Self::DivisionByZero { .. } => {
+                String::from("Attempt to divide by zero in a constant expression")
+            }
+            Self::NotCallable { found, .. } => {
+                format!("Cannot call a value of type '{}'", found)
+            }
+            Self::ArgumentCountMismatch {
+                expected, found, ..
+            } => {
+                format!("Expected {} argument(s), found {}", expected, found)
+            }
+            Self::UnclosedDelimiter { delimiter, .. } => {
+                let delimiter = match delimiter {
+                    TokenKind::LeftParenthesis => "(",
+                    TokenKind::LeftBracket => "[",
+                    _ => "{",
+                };
+                format!("Unclosed delimiter '{}'", delimiter)
+            }
+
+            Self::NonConstantArrayLength { .. } => {
+                String::from("Array length must be a constant integer expression")
+            }
+            Self::UnknownArgumentName { name, .. } => {
+                format!("No parameter named '{}'", name)
+            }
+            Self::DuplicateArgument { name, .. } => {
+                format!("Parameter '{}' is given more than once", name)
+            }
+            Self::PositionalAfterNamed { .. } => {
+                String::from("Positional argument after named arguments")
+            }
+            Self::MissingReturn { fn_name, .. } => {
+                format!(
+                    "Function '{}' does not return a value on every path",
+                    fn_name
+                )
+            }
+            Self::InvalidCast { from, to, .. } => {
+                format!("Cannot cast a value of type '{}' to '{}'", from, to)
+            }
+            Self::DuplicateParameter {
+                name,
+                original_span,
+                ..
+            } => {
+                format!(
+                    "Parameter '{}' already declared at '{}'",
+                    name,
+                    Span::format_span(*original_span)
+                )
+            }
+            Self::InvalidNumericLiteral { literal, .. } => {
+                format!("Invalid numeric literal '{}'", literal)
+            }
+            Self::IntegerLiteralTooLarge { literal, .. } => {
+                format!("Integer literal '{}' is too large to represent", literal)
+            }
+            Self::UnknownEnumVariant {
+                enum_name, variant, ..
+            } => {
+                format!("No variant '{}' in enum '{}'", variant, enum_name)
+            }
+            Self::NestedFunction { fn_name, .. } => format!(
+                "Function '{}' must be declared at the top level, not inside another function",
+                fn_name
+            ),
+            Self::ImportConflict { path, name, .. } => format!(
+                "'{}' from import '{}' conflicts with another declaration of the same name",
+                name, path
+            ),
+            Self::ArithmeticOverflow { ty, .. } => {
+                format!("Constant arithmetic overflows type '{}'", ty)
+            }
+            Self::UnsupportedInIR { construct, .. } => {
+                format!("{} cannot be lowered to IR yet", construct)
+            }
+            Self::DuplicateVariant {
+                name,
+                original_span,
+                ..
+            } => {
+                format!(
+                    "Variant '{}' already declared at '{}'",
+                    name,
+                    Span::format_span(*original_span)
+                )
+            }
+            Self::DuplicateField {
+                name,
+                original_span,
+                ..
+            } => {
+                format!(
+                    "Field '{}' already declared at '{}'",
+                    name,
+                    Span::format_span(*original_span)
+                )
+            }
         }
     }
 }
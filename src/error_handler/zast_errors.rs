@@ -1,8 +1,11 @@
 use core::fmt;
 
-use crate::lexer::tokens::{Span, TokenKind};
+use crate::{
+    lexer::tokens::{Span, TokenKind},
+    types::ValueType,
+};
 
-#[derive(Debug)]
+#[derive(Debug, PartialEq)]
 pub enum ZastError {
     // Parsing
     UnexpectedToken {
@@ -18,6 +21,12 @@ pub enum ZastError {
         span: Span,
         token_lexeme: String,
     },
+    UnterminatedStringLiteral {
+        span: Span,
+    },
+    UnterminatedBlockComment {
+        span: Span,
+    },
 
     // Sema
     VariableRedeclaration {
@@ -30,9 +39,174 @@ pub enum ZastError {
         fn_name: String,
         original_span: Span,
     },
+    UndeclaredVariable {
+        span: Span,
+        variable_name: String,
+    },
+    TypeMismatch {
+        span: Span,
+        expected: ValueType,
+        found: ValueType,
+    },
+    InvalidDereference {
+        span: Span,
+        found: ValueType,
+    },
+    AssignedButNeverRead {
+        span: Span,
+        variable_name: String,
+    },
+    UnresolvedImport {
+        span: Span,
+        path: String,
+    },
+    InvalidImport {
+        span: Span,
+        path: String,
+    },
+    NotIndexable {
+        span: Span,
+        found: ValueType,
+    },
+    NonIntegerIndex {
+        span: Span,
+        found: ValueType,
+    },
+    UnknownType {
+        span: Span,
+        type_name: String,
+    },
+    TypeRedeclaration {
+        span: Span,
+        type_name: String,
+    },
+    UnknownField {
+        span: Span,
+        found: ValueType,
+        field_name: String,
+    },
+    InvalidCharLiteral {
+        span: Span,
+    },
+    AssignToImmutable {
+        span: Span,
+        variable_name: String,
+    },
+    InvalidAssignmentTarget {
+        span: Span,
+    },
+    IntegerOutOfRange {
+        span: Span,
+        value: i64,
+        ty: ValueType,
+    },
+    InvalidNegation {
+        span: Span,
+        found: ValueType,
+    },
+    ReturnOutsideFunction {
+        span: Span,
+    },
+    BinaryTypeMismatch {
+        span: Span,
+        op: TokenKind,
+        left: ValueType,
+        right: ValueType,
+    },
+    UnusedVariable {
+        span: Span,
+        variable_name: String,
+    },
+    UnreachableCode {
+        span: Span,
+    },
+    DivisionByZero {
+        span: Span,
+    },
+    NotCallable {
+        span: Span,
+        found: ValueType,
+    },
+    ArgumentCountMismatch {
+        span: Span,
+        expected: usize,
+        found: usize,
+    },
+    UnclosedDelimiter {
+        span: Span,
+        delimiter: TokenKind,
+    },
+    NonConstantArrayLength {
+        span: Span,
+    },
+    UnknownArgumentName {
+        span: Span,
+        name: String,
+    },
+    DuplicateArgument {
+        span: Span,
+        name: String,
+    },
+    PositionalAfterNamed {
+        span: Span,
+    },
+    MissingReturn {
+        span: Span,
+        fn_name: String,
+    },
+    InvalidCast {
+        span: Span,
+        from: ValueType,
+        to: ValueType,
+    },
+    DuplicateParameter {
+        span: Span,
+        name: String,
+        original_span: Span,
+    },
+    InvalidNumericLiteral {
+        span: Span,
+        literal: String,
+    },
+    IntegerLiteralTooLarge {
+        span: Span,
+        literal: String,
+    },
+    UnknownEnumVariant {
+        span: Span,
+        enum_name: String,
+        variant: String,
+    },
+    NestedFunction {
+        span: Span,
+        fn_name: String,
+    },
+    ImportConflict {
+        span: Span,
+        path: String,
+        name: String,
+    },
+    ArithmeticOverflow {
+        span: Span,
+        ty: ValueType,
+    },
+    UnsupportedInIR {
+        span: Span,
+        construct: String,
+    },
+    DuplicateVariant {
+        span: Span,
+        name: String,
+        original_span: Span,
+    },
+    DuplicateField {
+        span: Span,
+        name: String,
+        original_span: Span,
+    },
 }
 
-#[derive(Debug)]
+#[derive(Debug, PartialEq)]
 pub enum Expected {
     Token(TokenKind),
     Concept(&'static str), // "type annotation", "expression"
@@ -1,10 +1,11 @@
 pub mod tokens;
 
 use crate::{
+    config::ZastConfig,
     error_handler::{ZastErrorCollector, zast_errors::ZastError},
     lexer::tokens::{Literal, Span, Token, TokenKind},
 };
-use std::mem;
+use std::{collections::HashMap, mem};
 
 /// A lexer for the Zast language.
 ///
@@ -15,16 +16,38 @@ use std::mem;
 /// # Errors
 ///
 /// If the lexer encounters characters it cannot classify, it emits
-/// [`TokenKind::Illegal`] tokens and continues. Accumulated errors are
-/// returned as a `Vec<String>` from [`ZastLexer::tokenize`].
+/// [`TokenKind::Illegal`] tokens, records a [`ZastError::IllegalToken`]
+/// carrying the offending lexeme and its span, and continues. Accumulated
+/// errors are returned as a [`ZastErrorCollector`] from [`ZastLexer::tokenize`].
+///
+/// # Identifiers
+///
+/// By default identifiers are ASCII only: a letter or `_`, followed by
+/// letters, digits, or `_`. With the `unicode-ident` feature they follow the
+/// Unicode identifier rules instead, starting with an `XID_Start` character
+/// or `_` and continuing with `XID_Continue` characters.
+///
+/// ```rust
+/// use zast::lexer::ZastLexer;
+///
+/// assert_eq!(
+///     ZastLexer::new("café").tokenize().is_ok(),
+///     cfg!(feature = "unicode-ident")
+/// );
+/// // a combining mark can continue an identifier but never start one
+/// assert!(ZastLexer::new("\u{301}cafe").tokenize().is_err());
+/// ```
 ///
 /// # Example
 ///
 /// ```rust
-/// let mut lexer = ZastLexer::new("10 + 3.14");
+/// use zast::lexer::ZastLexer;
+///
+/// let src = "10 + 3.14";
+/// let mut lexer = ZastLexer::new(src);
 /// match lexer.tokenize() {
 ///     Ok(tokens) => lexer.debug_tokens(tokens),
-///     Err(errors) => errors.iter().for_each(|e| eprintln!("Error: {e}")),
+///     Err(errors) => errors.report_all_errors(src),
 /// }
 /// ```
 pub struct ZastLexer {
@@ -45,6 +68,23 @@ pub struct ZastLexer {
 
     /// The 1-based column number of the current character within its line.
     current_column: usize,
+
+    /// Number of columns a tab advances `current_column` by.
+    tab_width: usize,
+
+    /// Span of the most recently produced token, used to place the EOF token.
+    last_span: Option<Span>,
+
+    /// Whether the EOF token has been produced by [`ZastLexer::next_token`].
+    eof_emitted: bool,
+
+    /// Reserved words and the token kinds they lex as. Any other word is an
+    /// identifier.
+    keywords: HashMap<String, TokenKind>,
+
+    /// Whether comments are emitted as [`TokenKind::LineComment`] and
+    /// [`TokenKind::BlockComment`] tokens instead of being skipped.
+    preserve_comments: bool,
 }
 
 impl ZastLexer {
@@ -57,16 +97,86 @@ impl ZastLexer {
     ///
     /// * `src` - The full source text to lex.
     pub fn new(src: &str) -> Self {
+        Self::with_config(src, &ZastConfig::default())
+    }
+
+    /// Creates a new `ZastLexer` that honors the lexing options in `config`,
+    /// such as [`ZastConfig::tab_width`] and [`ZastConfig::max_errors`].
+    ///
+    /// # Arguments
+    ///
+    /// * `src`    - The full source text to lex.
+    /// * `config` - The pipeline configuration.
+    pub fn with_config(src: &str, config: &ZastConfig) -> Self {
         Self {
             source: src.chars().collect(),
-            errors: ZastErrorCollector::new(),
+            errors: ZastErrorCollector::from_config(config),
             tokens: Vec::new(),
             current_source_pos: 0,
             current_line: 1,
             current_column: 1,
+            tab_width: config.tab_width,
+            last_span: None,
+            eof_emitted: false,
+            keywords: TokenKind::KEYWORDS
+                .iter()
+                .map(|(keyword, kind)| (keyword.to_string(), *kind))
+                .collect(),
+            preserve_comments: false,
         }
     }
 
+    /// Creates a new `ZastLexer` that recognizes `keywords` in place of the
+    /// default [`TokenKind::KEYWORDS`], e.g. to rename `fn` to `fun` for a
+    /// dialect. Words absent from the map lex as identifiers, so to extend
+    /// the defaults start from [`TokenKind::default_keywords`].
+    ///
+    /// # Arguments
+    ///
+    /// * `src`      - The full source text to lex.
+    /// * `keywords` - The reserved words and the token kinds they produce.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use zast::lexer::{ZastLexer, tokens::TokenKind};
+    ///
+    /// let mut keywords = TokenKind::default_keywords();
+    /// keywords.insert("fun", TokenKind::Fn);
+    ///
+    /// let tokens = ZastLexer::with_keywords("fun", keywords).tokenize().unwrap();
+    /// assert_eq!(tokens[0].kind, TokenKind::Fn);
+    /// ```
+    pub fn with_keywords(src: &str, keywords: HashMap<&str, TokenKind>) -> Self {
+        let mut lexer = Self::new(src);
+        lexer.keywords = keywords
+            .into_iter()
+            .map(|(keyword, kind)| (keyword.to_string(), kind))
+            .collect();
+        lexer
+    }
+
+    /// Creates a new `ZastLexer` that emits comments as
+    /// [`TokenKind::LineComment`] and [`TokenKind::BlockComment`] tokens,
+    /// for formatters and documentation tools that need their text and
+    /// position. [`ZastParser`](crate::parser::ZastParser) ignores these
+    /// tokens, so the output can still be parsed.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use zast::lexer::{ZastLexer, tokens::TokenKind};
+    ///
+    /// let tokens = ZastLexer::with_comments_preserved("x // note").tokenize().unwrap();
+    /// assert_eq!(tokens[1].kind, TokenKind::LineComment);
+    /// assert_eq!(tokens[1].lexeme, "// note");
+    /// ```
+    pub fn with_comments_preserved(src: &str) -> Self {
+        let mut lexer = Self::new(src);
+        lexer.preserve_comments = true;
+        lexer
+    }
+
     /// Prints a human-readable debug representation of a token sequence.
     ///
     /// Outputs each token's literal value, kind, and source span in the format:
@@ -91,25 +201,68 @@ impl ZastLexer {
     /// Tokenizes the entire source text.
     ///
     /// Drives the lexer from the start of the source to the end, producing
-    /// one [`Token`] per lexeme. Whitespace and newlines are skipped between
-    /// tokens. Line and column counters are updated as the source is consumed.
+    /// one [`Token`] per lexeme. Whitespace, newlines, and block comments are
+    /// skipped between tokens. Line and column counters are updated as the source is consumed.
     ///
     /// # Returns
     ///
     /// - `Ok(Vec<Token>)` if no errors were encountered.
-    /// - `Err(Vec<String>)` containing all accumulated error messages if any
-    ///   illegal characters were encountered.
+    /// - `Err(ZastErrorCollector)` containing all accumulated lexical errors
+    ///   otherwise. This is the same error type returned by the parser and the
+    ///   semantic analyzer, so every phase reports through one path.
+    ///
+    /// ```rust
+    /// use zast::{error_handler::ZastErrorCollector, lexer::ZastLexer, parser::ZastParser};
+    ///
+    /// fn report(errors: &ZastErrorCollector, src: &str) -> String {
+    ///     errors.render_all(src).concat()
+    /// }
+    ///
+    /// let lexical = "let x = @;";
+    /// let syntax = "let x = ;";
+    /// let lexer_errors = ZastLexer::new(lexical).tokenize().unwrap_err();
+    /// let parser_errors = ZastParser::from_source(syntax).unwrap_err();
+    ///
+    /// assert!(report(&lexer_errors, lexical).starts_with("Error at: 1:9 |"));
+    /// assert!(report(&parser_errors, syntax).starts_with("Error at: 1:9 |"));
+    /// ```
+    ///
+    /// An unrecognized character is reported with its own span:
+    ///
+    /// ```rust
+    /// use zast::{error_handler::zast_errors::ZastError, lexer::{ZastLexer, tokens::Span}};
+    ///
+    /// let errors = ZastLexer::new("let x = @;").tokenize().unwrap_err();
+    /// assert_eq!(
+    ///     errors.errors(),
+    ///     [ZastError::IllegalToken {
+    ///         span: Span { col_start: 9, col_end: 9, ln_start: 1, ln_end: 1 },
+    ///         token_lexeme: String::from("@"),
+    ///     }]
+    /// );
+    /// ```
+    ///
+    /// Tokenizing never panics, whatever the input: anything the lexer cannot
+    /// make sense of is reported as an error instead, so untrusted source is
+    /// safe to lex.
+    ///
+    /// ```rust
+    /// use zast::lexer::ZastLexer;
+    ///
+    /// let adversarial = [
+    ///     "", "\0", "\r", "'", "'\\", "\"", "\"\\", "/*", "/* *", "/", "&", ".",
+    ///     "1.", "1..2", "1e", "1u", "0x", "99999999999999999999",
+    ///     "99999999999999999999i64", "1f32f32", "\u{301}", "\u{1F600}", "\t\t\n\r\n",
+    /// ];
+    ///
+    /// for src in adversarial {
+    ///     let _ = ZastLexer::new(src).tokenize();
+    /// }
+    /// ```
     pub fn tokenize(&mut self) -> Result<Vec<Token>, ZastErrorCollector> {
-        while !self.is_at_end() {
-            self.skip_whitespaces();
-
-            let token = self.read_token();
+        while let Some(token) = self.next_token() {
             self.tokens.push(token);
-
-            self.skip_whitespaces();
         }
-        self.tokens
-            .push(self.new_token(TokenKind::Eof, String::from("END_OF_FILE")));
 
         if self.errors.has_errors() {
             Err(mem::take(&mut self.errors))
@@ -118,12 +271,70 @@ impl ZastLexer {
         }
     }
 
+    /// Scans and returns the next token, for consumers that lex lazily such
+    /// as syntax highlighters.
+    ///
+    /// Whitespace and block comments before the token are skipped. Once the input is exhausted a
+    /// single [`TokenKind::Eof`] token is returned, and every call after that
+    /// returns `None`. Lexical errors still produce [`TokenKind::Illegal`]
+    /// tokens and are recorded as they would be by [`ZastLexer::tokenize`].
+    ///
+    /// ```rust
+    /// use zast::lexer::ZastLexer;
+    ///
+    /// for src in ["", "let x = 1;", "fn f(): i32 {\n    return 2 ** 3;\n}", "a /* b */ <= 'c'"] {
+    ///     let describe = |tokens: Vec<_>| -> Vec<String> {
+    ///         tokens.into_iter().map(|token| format!("{:?}", token)).collect()
+    ///     };
+    ///     let lazy = describe(ZastLexer::new(src).collect());
+    ///     let eager = describe(ZastLexer::new(src).tokenize().unwrap());
+    ///     assert_eq!(lazy, eager);
+    /// }
+    ///
+    /// let mut lexer = ZastLexer::new("x");
+    /// assert_eq!(lexer.by_ref().count(), 2);
+    /// assert!(lexer.next_token().is_none());
+    /// ```
+    pub fn next_token(&mut self) -> Option<Token> {
+        if self.eof_emitted {
+            return None;
+        }
+
+        self.skip_whitespaces();
+
+        let token = if self.is_at_end() {
+            self.eof_emitted = true;
+            self.eof_token()
+        } else {
+            self.read_token()
+        };
+
+        self.last_span = Some(token.span);
+        Some(token)
+    }
+
     /// Dispatches to the appropriate sub-lexer based on the current character.
     ///
     /// Numeric characters are routed to [`ZastLexer::tokenize_number`],
     /// alphabetic characters and underscores to [`ZastLexer::tokenize_keyword`],
     /// and all known punctuation and operators are matched directly. Unrecognized
     /// characters produce an [`TokenKind::Illegal`] token.
+    ///
+    /// Only called with input remaining: [`ZastLexer::next_token`] checks
+    /// [`ZastLexer::is_at_end`] after skipping whitespace and produces the
+    /// EOF token instead.
+    ///
+    /// ```rust
+    /// use zast::lexer::{ZastLexer, tokens::TokenKind};
+    ///
+    /// // the source ends on the last character of a token
+    /// for src in ["42", "x", "a+b", "\"s\""] {
+    ///     let tokens = ZastLexer::new(src).tokenize().unwrap();
+    ///     let last = &tokens[tokens.len() - 2];
+    ///     assert_eq!(last.span.col_end, src.len());
+    ///     assert_eq!(tokens.last().unwrap().kind, TokenKind::Eof);
+    /// }
+    /// ```
     fn read_token(&mut self) -> Token {
         let cur = self.current_char();
         let strc = String::from(cur);
@@ -136,33 +347,57 @@ impl ZastLexer {
             return self.tokenize_keyword();
         }
 
+        if cur == '"' {
+            return self.tokenize_string();
+        }
+
+        if cur == '\'' {
+            return self.tokenize_char();
+        }
+
+        // only reached when comments are preserved; otherwise they were skipped
+        if self.is_at_comment() {
+            return self.tokenize_comment();
+        }
+
         let tok = match cur {
             ';' => self.new_token(TokenKind::Semicolon, strc),
             ':' => self.new_token(TokenKind::Colon, strc),
-            '&' => self.new_token(TokenKind::Ampersand, strc),
-            '=' => self.new_token(TokenKind::Assignment, strc),
+            '?' => self.new_token(TokenKind::Question, strc),
+            '&' => self.match_two('&', TokenKind::LogicalAnd, TokenKind::Ampersand),
+            '|' => self.match_two('|', TokenKind::LogicalOr, TokenKind::Pipe),
+            '=' => self.match_two('=', TokenKind::Equal, TokenKind::Assignment),
+            '!' => self.match_two('=', TokenKind::NotEqual, TokenKind::Bang),
+            '<' if self.peek_char() == '<' => self.match_two('<', TokenKind::Shl, TokenKind::Less),
+            '<' => self.match_two('=', TokenKind::LessEqual, TokenKind::Less),
+            '>' if self.peek_char() == '>' => {
+                self.match_two('>', TokenKind::Shr, TokenKind::Greater)
+            }
+            '>' => self.match_two('=', TokenKind::GreaterEqual, TokenKind::Greater),
             '.' => self.new_token(TokenKind::Dot, strc),
             ',' => self.new_token(TokenKind::Comma, strc),
-            '+' => self.new_token(TokenKind::Plus, strc),
-            '-' => self.new_token(TokenKind::Minus, strc),
-            '*' => self.new_token(TokenKind::Multiply, strc),
-            '/' => self.new_token(TokenKind::Divide, strc),
+            '+' => self.match_two('=', TokenKind::PlusAssign, TokenKind::Plus),
+            '-' => self.match_two('=', TokenKind::MinusAssign, TokenKind::Minus),
+            '*' if self.peek_char() == '*' => {
+                self.match_two('*', TokenKind::DoubleStar, TokenKind::Multiply)
+            }
+            '*' => self.match_two('=', TokenKind::MultiplyAssign, TokenKind::Multiply),
+            '/' => self.match_two('=', TokenKind::DivideAssign, TokenKind::Divide),
+            '^' => self.new_token(TokenKind::Caret, strc),
             '(' => self.new_token(TokenKind::LeftParenthesis, strc),
             ')' => self.new_token(TokenKind::RightParenthesis, strc),
             '{' => self.new_token(TokenKind::LeftBrace, strc),
             '}' => self.new_token(TokenKind::RightBrace, strc),
+            '[' => self.new_token(TokenKind::LeftBracket, strc),
+            ']' => self.new_token(TokenKind::RightBracket, strc),
             _ => {
+                let tok = self.new_token(TokenKind::Illegal, strc);
                 self.throw_error(ZastError::IllegalToken {
-                    span: self.get_span(
-                        self.current_column - 1,
-                        self.current_column,
-                        self.current_line,
-                        self.current_line,
-                    ),
-                    token_lexeme: strc.clone(),
+                    span: tok.span,
+                    token_lexeme: tok.lexeme.clone(),
                 });
 
-                self.new_token(TokenKind::Illegal, strc)
+                tok
             }
         };
 
@@ -177,26 +412,41 @@ impl ZastLexer {
     /// initial alphabetic character. The resulting string is matched against
     /// the set of reserved keywords via [`Token::from_keyword`]; if no keyword
     /// matches, an identifier token is produced instead.
+    ///
+    /// The scan stops on the first character that is not part of the lexeme,
+    /// so the current column is always one past the token's last character.
+    ///
+    /// ```rust
+    /// use zast::lexer::ZastLexer;
+    ///
+    /// let columns = |src: &str| -> Vec<(usize, usize)> {
+    ///     let tokens = ZastLexer::new(src).tokenize().unwrap();
+    ///     tokens.iter().map(|token| (token.span.col_start, token.span.col_end)).collect()
+    /// };
+    ///
+    /// assert_eq!(columns("foo"), [(1, 3), (4, 4)]);
+    /// assert_eq!(columns("foo;"), [(1, 3), (4, 4), (5, 5)]);
+    /// assert_eq!(columns("12;x 345"), [(1, 2), (3, 3), (4, 4), (6, 8), (9, 9)]);
+    /// ```
     fn tokenize_keyword(&mut self) -> Token {
         let col_start = self.current_column;
         let ln_start = self.current_line;
         let src_start = self.current_source_pos;
 
-        while !self.is_at_end() && self.is_alphanumeric(self.peek_char()) {
+        while !self.is_at_end() && self.is_alphanumeric(self.current_char()) {
             self.advance();
         }
 
-        self.advance();
-
         let col_end = self.current_column - 1;
         let ln_end = self.current_line;
         let src_end = self.current_source_pos;
 
         let keyword_src: String = self.source[src_start..src_end].iter().collect();
 
-        Token::from_keyword(
+        Token::from_keyword_in(
             &keyword_src,
             self.get_span(col_start, col_end, ln_start, ln_end),
+            &self.keywords,
         )
     }
 
@@ -207,59 +457,315 @@ impl ZastLexer {
     /// continues scanning and produces a [`TokenKind::Float`]. Otherwise it
     /// produces a [`TokenKind::Integer`].
     ///
-    /// # Panics
+    /// The literal may end in a type suffix that pins its type, e.g. `10u8`,
+    /// `5i64`, or `3.0f32`. Integers accept integer and float suffixes, so
+    /// `3f32` is a float; floats accept only float suffixes. The suffix is
+    /// kept in the lexeme and read back with [`Token::literal_suffix`].
+    ///
+    /// A literal running straight into a word that is not a valid suffix,
+    /// e.g. `3foo` or `2.0u8`, is reported as a
+    /// [`ZastError::InvalidNumericLiteral`] covering the whole word, and an
+    /// [`TokenKind::Illegal`] token is produced instead of a number followed
+    /// by an identifier.
+    ///
+    /// ```rust
+    /// use zast::lexer::ZastLexer;
+    ///
+    /// assert!(ZastLexer::new("200u8").tokenize().is_ok());
+    /// assert!(ZastLexer::new("3foo").tokenize().is_err());
+    /// ```
     ///
-    /// Panics if the scanned slice cannot be parsed as `i32` or `f64`. This
-    /// should not occur under normal operation since only digit characters are
-    /// consumed.
+    /// Like [`ZastLexer::tokenize_keyword`], the scan stops on the first
+    /// character past the literal, including at the end of input.
+    ///
+    /// An integer literal that does not fit in an `i64` emits a
+    /// [`ZastError::IntegerLiteralTooLarge`] and produces an
+    /// [`TokenKind::Illegal`] token; float literals saturate to infinity.
+    ///
+    /// ```rust
+    /// use zast::lexer::ZastLexer;
+    ///
+    /// assert!(ZastLexer::new("9223372036854775807").tokenize().is_ok());
+    /// assert!(ZastLexer::new("9223372036854775808").tokenize().is_err());
+    /// ```
     fn tokenize_number(&mut self) -> Token {
         let col_start = self.current_column;
         let ln_start = self.current_line;
         let src_start = self.current_source_pos;
 
-        while !self.is_at_end() && self.is_number(self.peek_char()) {
+        while !self.is_at_end() && self.is_number(self.current_char()) {
             self.advance();
         }
 
-        self.advance();
-
-        if self.current_char_is('.') && self.is_number(self.peek_char()) {
+        let mut is_float = false;
+        if !self.is_at_end() && self.current_char_is('.') && self.is_number(self.peek_char()) {
+            is_float = true;
             self.advance(); // consume '.'
 
-            while self.is_number(self.peek_char()) {
+            while !self.is_at_end() && self.is_number(self.current_char()) {
                 self.advance();
             }
+        }
+
+        let digits: String = self.source[src_start..self.current_source_pos]
+            .iter()
+            .collect();
 
+        if let Some(suffix) = self.peek_type_suffix()
+            && (suffix.starts_with('f') || !is_float)
+        {
+            is_float |= suffix.starts_with('f');
+
+            for _ in 0..suffix.len() {
+                self.advance();
+            }
+        }
+
+        let malformed = !self.is_at_end() && self.is_alphanumeric(self.current_char());
+        while !self.is_at_end() && self.is_alphanumeric(self.current_char()) {
             self.advance();
+        }
 
-            let col_end = self.current_column - 1;
-            let ln_end = self.current_line;
-            let src_end = self.current_source_pos;
+        let col_end = self.current_column - 1;
+        let ln_end = self.current_line;
+        let lexeme: String = self.source[src_start..self.current_source_pos]
+            .iter()
+            .collect();
 
-            let num_lit: String = self.source[src_start..src_end].iter().collect();
-            let num = num_lit.parse::<f64>().unwrap();
+        if malformed {
+            let span = self.get_span(col_start, col_end, ln_start, ln_end);
+            self.throw_error(ZastError::InvalidNumericLiteral {
+                span,
+                literal: lexeme.clone(),
+            });
 
-            Token {
-                literal: Literal::FloatValue(num),
-                lexeme: num_lit,
-                kind: TokenKind::Float,
-                span: self.get_span(col_start, col_end, ln_start, ln_end),
-            }
+            return Token {
+                literal: Literal::None,
+                lexeme,
+                kind: TokenKind::Illegal,
+                span,
+            };
+        }
+
+        let span = self.get_span(col_start, col_end, ln_start, ln_end);
+        // only digits and a '.' were scanned, so this fails only on overflow
+        let literal = if is_float {
+            digits.parse().ok().map(Literal::FloatValue)
         } else {
-            let col_end = self.current_column - 1;
-            let ln_end = self.current_line;
-            let src_end = self.current_source_pos;
-
-            let num_lit: String = self.source[src_start..src_end].iter().collect();
-            let num = num_lit.parse::<i64>().unwrap();
-
-            Token {
-                literal: Literal::IntegerValue(num),
-                lexeme: num_lit,
-                kind: TokenKind::Integer,
-                span: self.get_span(col_start, col_end, ln_start, ln_end),
+            digits.parse().ok().map(Literal::IntegerValue)
+        };
+
+        let Some(literal) = literal else {
+            self.throw_error(ZastError::IntegerLiteralTooLarge {
+                span,
+                literal: lexeme.clone(),
+            });
+
+            return Token {
+                literal: Literal::None,
+                lexeme,
+                kind: TokenKind::Illegal,
+                span,
+            };
+        };
+
+        Token {
+            literal,
+            lexeme,
+            kind: if is_float {
+                TokenKind::Float
+            } else {
+                TokenKind::Integer
+            },
+            span,
+        }
+    }
+
+    /// Returns the numeric type suffix starting at the current position, such
+    /// as `u8` or `f32`, without consuming it. Returns `None` unless the whole
+    /// word starting here names a numeric type.
+    fn peek_type_suffix(&self) -> Option<String> {
+        let word: String = self.source[self.current_source_pos..]
+            .iter()
+            .take_while(|c| self.is_alphanumeric(**c))
+            .collect();
+
+        let is_suffix = match word.as_str() {
+            "isize" | "usize" => true,
+            _ => {
+                let width = word.get(1..).and_then(|bits| bits.parse::<u16>().ok());
+                match word.chars().next() {
+                    Some('i' | 'u') => matches!(width, Some(8 | 16 | 32 | 64 | 128)),
+                    Some('f') => matches!(width, Some(16 | 32 | 64 | 128)),
+                    _ => false,
+                }
+            }
+        };
+
+        is_suffix.then_some(word)
+    }
+
+    /// Scans a double-quoted string literal, e.g. `"math.zast"`.
+    ///
+    /// Consumes the opening `"`, every character up to the matching closing
+    /// `"`, and the closing `"` itself. The escape sequences `\n`, `\t`, `\\`,
+    /// `\"`, and `\0` are decoded into the [`Literal::StringValue`]; the lexeme
+    /// keeps the raw source text including the quotes.
+    ///
+    /// If the input ends before the closing quote, a
+    /// [`ZastError::UnterminatedStringLiteral`] is emitted and an
+    /// [`TokenKind::Illegal`] token is produced instead.
+    ///
+    /// A string may span several lines; its span then ends on the line and
+    /// column of the closing quote:
+    ///
+    /// ```rust
+    /// use zast::lexer::{ZastLexer, tokens::Span};
+    ///
+    /// let tokens = ZastLexer::new("x \"ab\ncd\" y").tokenize().unwrap();
+    ///
+    /// assert_eq!(tokens[1].span, Span { ln_start: 1, ln_end: 2, col_start: 3, col_end: 3 });
+    /// assert_eq!(tokens[2].span, Span { ln_start: 2, ln_end: 2, col_start: 5, col_end: 5 });
+    /// ```
+    fn tokenize_string(&mut self) -> Token {
+        let col_start = self.current_column;
+        let ln_start = self.current_line;
+        let src_start = self.current_source_pos;
+
+        self.advance(); // eat opening '"'
+
+        let mut value = String::new();
+        while !self.is_at_end() && !self.current_char_is('"') {
+            match self.current_char() {
+                '\\' => {
+                    self.advance(); // eat '\'
+                    if self.is_at_end() {
+                        break;
+                    }
+                    value.push(match self.current_char() {
+                        'n' => '\n',
+                        't' => '\t',
+                        '0' => '\0',
+                        c => c,
+                    });
+                    self.advance();
+                }
+                c => {
+                    value.push(c);
+                    self.advance();
+                }
+            }
+        }
+
+        if self.is_at_end() {
+            let (ln_end, col_end) = self.last_char_position();
+            let span = self.get_span(col_start, col_end, ln_start, ln_end);
+            let lexeme: String = self.source[src_start..].iter().collect();
+            self.throw_error(ZastError::UnterminatedStringLiteral { span });
+
+            return Token {
+                literal: Literal::None,
+                lexeme,
+                kind: TokenKind::Illegal,
+                span,
+            };
+        }
+
+        self.advance(); // eat closing '"'
+
+        let col_end = self.current_column - 1;
+        let ln_end = self.current_line;
+        let src_end = self.current_source_pos;
+
+        Token {
+            literal: Literal::StringValue(value),
+            lexeme: self.source[src_start..src_end].iter().collect(),
+            kind: TokenKind::String,
+            span: self.get_span(col_start, col_end, ln_start, ln_end),
+        }
+    }
+
+    /// Scans a single-quoted char literal, e.g. `'a'`, `'\n'`.
+    ///
+    /// Consumes the opening `'`, the character (or escape sequence), and the
+    /// closing `'`. The escape sequences `\n`, `\t`, `\\`, `\'`, and `\0` are
+    /// decoded into the [`Literal::CharValue`]; the lexeme keeps the raw
+    /// source text including the quotes.
+    ///
+    /// Scanning stops at a newline, so an unterminated literal never swallows
+    /// the rest of the file. If the literal is unterminated, empty, or holds
+    /// more than one character, a [`ZastError::InvalidCharLiteral`] is emitted
+    /// and an [`TokenKind::Illegal`] token is produced instead.
+    ///
+    /// ```rust
+    /// use zast::lexer::{ZastLexer, tokens::{Literal, TokenKind}};
+    ///
+    /// for (src, value) in [("'a'", 'a'), ("'\\n'", '\n'), ("'\\''", '\'')] {
+    ///     let tokens = ZastLexer::new(src).tokenize().unwrap();
+    ///     assert_eq!(tokens[0].kind, TokenKind::Char);
+    ///     assert!(matches!(tokens[0].literal, Literal::CharValue(c) if c == value));
+    /// }
+    ///
+    /// let errors = ZastLexer::new("'ab'").tokenize().unwrap_err();
+    /// assert!(errors.render_all("'ab'")[0].starts_with("Error at: 1:1-4 | Invalid char literal"));
+    /// ```
+    fn tokenize_char(&mut self) -> Token {
+        let col_start = self.current_column;
+        let ln_start = self.current_line;
+        let src_start = self.current_source_pos;
+
+        self.advance(); // eat opening '\''
+
+        let mut chars = Vec::new();
+        while !self.is_at_end() && !self.current_char_is('\'') && !self.current_char_is('\n') {
+            match self.current_char() {
+                '\\' => {
+                    self.advance(); // eat '\'
+                    if self.is_at_end() {
+                        break;
+                    }
+                    chars.push(match self.current_char() {
+                        'n' => '\n',
+                        't' => '\t',
+                        '0' => '\0',
+                        c => c,
+                    });
+                    self.advance();
+                }
+                c => {
+                    chars.push(c);
+                    self.advance();
+                }
             }
         }
+
+        let terminated = !self.is_at_end() && self.current_char_is('\'');
+        if terminated {
+            self.advance(); // eat closing '\''
+        }
+
+        let span = self.get_span(col_start, self.current_column - 1, ln_start, ln_start);
+        let lexeme: String = self.source[src_start..self.current_source_pos]
+            .iter()
+            .collect();
+
+        if !terminated || chars.len() != 1 {
+            self.throw_error(ZastError::InvalidCharLiteral { span });
+
+            return Token {
+                literal: Literal::None,
+                lexeme,
+                kind: TokenKind::Illegal,
+                span,
+            };
+        }
+
+        Token {
+            literal: Literal::CharValue(chars[0]),
+            lexeme,
+            kind: TokenKind::Char,
+            span,
+        }
     }
 
     /// Pushes the error to the `ZastErrorCollector`
@@ -267,29 +773,172 @@ impl ZastLexer {
         self.errors.add_error(error);
     }
 
-    /// Advances past any whitespace characters, updating line and column state.
+    /// Advances past any whitespace characters (spaces, tabs, `\r`, and `\n`)
+    /// and comments. When comments are preserved, stops at a comment instead
+    /// so it is lexed as a token.
     ///
-    /// Newlines (`\n`) increment the line counter and reset the column to 1.
-    /// Carriage returns, tabs, and spaces are consumed silently.
+    /// Line and column state is updated by [`ZastLexer::advance`].
     fn skip_whitespaces(&mut self) {
         while !self.is_at_end() {
-            match self.current_char() {
-                '\n' => {
-                    self.advance();
-                    self.current_line += 1;
-                    self.current_column = 1;
-                }
-                '\r' | '\t' | ' ' => {
-                    self.advance();
-                }
-                _ => break,
+            if matches!(self.current_char(), '\n' | '\t' | '\r' | ' ') {
+                self.advance();
+            } else if self.is_at_comment() && !self.preserve_comments {
+                self.skip_comment();
+            } else {
+                break;
+            }
+        }
+    }
+
+    /// Returns `true` if a `//` or `/*` comment starts at the current position.
+    fn is_at_comment(&self) -> bool {
+        self.current_char_is('/') && matches!(self.peek_char(), '/' | '*')
+    }
+
+    /// Scans a comment into a [`TokenKind::LineComment`] or
+    /// [`TokenKind::BlockComment`] token. The lexeme is the full comment and
+    /// the [`Literal::StringValue`] its text without the delimiters.
+    fn tokenize_comment(&mut self) -> Token {
+        let col_start = self.current_column;
+        let ln_start = self.current_line;
+        let src_start = self.current_source_pos;
+
+        let kind = if self.peek_char() == '/' {
+            TokenKind::LineComment
+        } else {
+            TokenKind::BlockComment
+        };
+        let terminated = self.skip_comment();
+
+        let (ln_end, col_end) = self.last_char_position();
+        let lexeme: String = self.source[src_start..self.current_source_pos]
+            .iter()
+            .collect();
+
+        let close_len = if kind == TokenKind::BlockComment && terminated {
+            2
+        } else {
+            0
+        };
+        let text: String = self.source[src_start + 2..self.current_source_pos - close_len]
+            .iter()
+            .collect();
+
+        Token {
+            literal: Literal::StringValue(text),
+            lexeme,
+            kind,
+            span: self.get_span(col_start, col_end, ln_start, ln_end),
+        }
+    }
+
+    /// Skips the `//` or `/*` comment starting at the current position,
+    /// returning `false` if it is an unterminated block comment.
+    ///
+    /// A line comment runs up to, but not including, the next newline.
+    fn skip_comment(&mut self) -> bool {
+        if self.peek_char() == '/' {
+            while !self.is_at_end() && !self.current_char_is('\n') {
+                self.advance();
             }
+            return true;
         }
+
+        self.skip_block_comment()
+    }
+
+    /// Skips a block comment, e.g. `/* note */`, starting at its opening `/*`.
+    ///
+    /// Block comments nest: every `/*` inside the comment must be matched by
+    /// its own `*/`, so `/* outer /* inner */ still comment */` is a single
+    /// comment. If the input ends while any comment is still open, a
+    /// [`ZastError::UnterminatedBlockComment`] is reported at the outermost
+    /// opening `/*` and `false` is returned.
+    ///
+    /// ```rust
+    /// use zast::lexer::{ZastLexer, tokens::TokenKind};
+    ///
+    /// let tokens = ZastLexer::new("/* outer /* inner */ still comment */ 42").tokenize().unwrap();
+    /// let kinds: Vec<_> = tokens.iter().map(|token| token.kind).collect();
+    /// assert_eq!(kinds, [TokenKind::Integer, TokenKind::Eof]);
+    ///
+    /// let errors = ZastLexer::new("/* outer /* inner */ 42").tokenize().unwrap_err();
+    /// let [unterminated] = errors.errors() else { unreachable!() };
+    /// assert_eq!(unterminated.get_error_code(), "E0031");
+    /// assert_eq!((unterminated.get_span().col_start, unterminated.get_span().col_end), (1, 2));
+    /// ```
+    fn skip_block_comment(&mut self) -> bool {
+        let col_start = self.current_column;
+        let ln_start = self.current_line;
+
+        self.advance(); // eat '/'
+        self.advance(); // eat '*'
+
+        let mut depth = 1;
+        while !self.is_at_end() && depth > 0 {
+            if self.current_char_is('/') && self.peek_char() == '*' {
+                depth += 1;
+                self.advance();
+            } else if self.current_char_is('*') && self.peek_char() == '/' {
+                depth -= 1;
+                self.advance();
+            }
+            self.advance();
+        }
+
+        if depth > 0 {
+            let span = self.get_span(col_start, col_start + 1, ln_start, ln_start);
+            self.throw_error(ZastError::UnterminatedBlockComment { span });
+            return false;
+        }
+
+        true
     }
 
     /// Returns the character at the current position without advancing.
+    ///
+    /// Returns `'\0'` once the end of the source has been reached, like
+    /// [`ZastLexer::peek_char`], so empty and whitespace-only input never
+    /// index past the end.
+    ///
+    /// ```rust
+    /// use zast::lexer::{ZastLexer, tokens::TokenKind};
+    ///
+    /// for src in ["", "   \n  "] {
+    ///     let tokens = ZastLexer::new(src).tokenize().unwrap();
+    ///     let kinds: Vec<_> = tokens.iter().map(|token| token.kind).collect();
+    ///     assert_eq!(kinds, [TokenKind::Eof]);
+    /// }
+    /// ```
     fn current_char(&self) -> char {
-        self.source[self.current_source_pos]
+        self.source
+            .get(self.current_source_pos)
+            .copied()
+            .unwrap_or('\0')
+    }
+
+    /// Returns the line and column of the most recently consumed character,
+    /// for the end of a token's span.
+    ///
+    /// Usually that is one column to the left of the current position. If the
+    /// last character was a newline, as when a multi-line string runs into the
+    /// end of input, it is the newline itself at the end of the previous line
+    /// rather than column `0` of the current one.
+    fn last_char_position(&self) -> (usize, usize) {
+        if self.current_column > 1 || self.current_source_pos == 0 {
+            return (self.current_line, self.current_column.saturating_sub(1));
+        }
+
+        let newline_pos = self.current_source_pos - 1;
+        let line_start = self.source[..newline_pos]
+            .iter()
+            .rposition(|c| *c == '\n')
+            .map_or(0, |pos| pos + 1);
+        let col = (line_start..=newline_pos)
+            .map(|pos| self.column_width(pos))
+            .sum();
+
+        (self.current_line - 1, col)
     }
 
     /// Returns `true` if the character at the current position equals `c`.
@@ -308,13 +957,58 @@ impl ZastLexer {
         self.source[self.current_source_pos + 1]
     }
 
-    /// Advances the lexer by one character, updating source position and column.
+    /// Advances the lexer by one character, updating source position, line,
+    /// and column.
+    ///
+    /// This is the only place position state changes, so every consumed
+    /// character is accounted for the same way regardless of which sub-lexer
+    /// consumes it:
+    /// - `\n` increments the line counter and resets the column to 1
+    /// - anything else advances the column by its [width](ZastLexer::column_width)
     ///
     /// Has no effect if the lexer is already at the end of the source.
+    ///
+    /// ```rust
+    /// use zast::lexer::ZastLexer;
+    ///
+    /// let tokens = ZastLexer::new("x\n/* one\n two */\n  y").tokenize().unwrap();
+    /// let span = tokens[1].span;
+    ///
+    /// assert_eq!((span.ln_start, span.col_start, span.ln_end, span.col_end), (4, 3, 4, 3));
+    /// ```
     fn advance(&mut self) {
-        if !self.is_at_end() {
-            self.current_source_pos += 1;
-            self.current_column += 1;
+        if self.is_at_end() {
+            return;
+        }
+
+        match self.current_char() {
+            '\n' => {
+                self.current_line += 1;
+                self.current_column = 1;
+            }
+            _ => self.current_column += self.column_width(self.current_source_pos),
+        }
+
+        self.current_source_pos += 1;
+    }
+
+    /// Returns the number of columns the character at `pos` occupies: the
+    /// configured tab width for `\t`, none for the `\r` of a `\r\n` pair, so
+    /// a Windows line break counts as a single `\n`, and one otherwise.
+    ///
+    /// ```rust
+    /// use zast::lexer::ZastLexer;
+    ///
+    /// let tokens = ZastLexer::new("a\r\nbc").tokenize().unwrap();
+    /// let span = tokens[1].span;
+    ///
+    /// assert_eq!((span.ln_start, span.col_start, span.col_end), (2, 1, 2));
+    /// ```
+    fn column_width(&self, pos: usize) -> usize {
+        match self.source[pos] {
+            '\t' => self.tab_width,
+            '\r' if self.source.get(pos + 1) == Some(&'\n') => 0,
+            _ => 1,
         }
     }
 
@@ -328,17 +1022,35 @@ impl ZastLexer {
         c.is_ascii_digit()
     }
 
-    /// Returns `true` if `c` can begin an identifier: an alphabetic character or `_`.
+    /// Returns `true` if `c` can begin an identifier: an ASCII letter or `_`.
+    #[cfg(not(feature = "unicode-ident"))]
     fn is_alphabetic(&self, c: char) -> bool {
-        c.is_alphabetic() || c == '_'
+        c.is_ascii_alphabetic() || c == '_'
     }
 
-    /// Returns `true` if `c` is valid inside an identifier: alphabetic, digit, or `_`.
+    /// Returns `true` if `c` can begin an identifier: an `XID_Start`
+    /// character or `_`.
+    #[cfg(feature = "unicode-ident")]
+    fn is_alphabetic(&self, c: char) -> bool {
+        unicode_ident::is_xid_start(c) || c == '_'
+    }
+
+    /// Returns `true` if `c` is valid inside an identifier: an ASCII letter,
+    /// digit, or `_`.
+    #[cfg(not(feature = "unicode-ident"))]
     fn is_alphanumeric(&self, c: char) -> bool {
         self.is_alphabetic(c) || self.is_number(c)
     }
 
-    /// Constructs a [`Span`] from explicit line and column bounds.
+    /// Returns `true` if `c` is valid inside an identifier: an
+    /// `XID_Continue` character, which includes digits and `_`.
+    #[cfg(feature = "unicode-ident")]
+    fn is_alphanumeric(&self, c: char) -> bool {
+        unicode_ident::is_xid_continue(c)
+    }
+
+    /// Constructs a [`Span`] from explicit line and column bounds, with
+    /// `col_end` the column of the token's last character.
     fn get_span(&self, col_start: usize, col_end: usize, ln_start: usize, ln_end: usize) -> Span {
         Span {
             col_start,
@@ -348,6 +1060,90 @@ impl ZastLexer {
         }
     }
 
+    /// Constructs a one- or two-character operator token starting at the
+    /// current position, e.g. `<` or `<=`.
+    ///
+    /// If the next character is `second`, both characters are consumed into a
+    /// `two` token; otherwise a single-character `one` token is produced. Like
+    /// [`ZastLexer::new_token`], the current position is left on the token's
+    /// last character for [`ZastLexer::read_token`] to advance past.
+    ///
+    /// ```rust
+    /// use zast::lexer::{ZastLexer, tokens::TokenKind};
+    ///
+    /// let tokens = ZastLexer::new("a <= b < c").tokenize().unwrap();
+    ///
+    /// assert_eq!(tokens[1].kind, TokenKind::LessEqual);
+    /// assert_eq!((tokens[1].span.col_start, tokens[1].span.col_end), (3, 4));
+    /// assert_eq!(tokens[3].kind, TokenKind::Less);
+    /// assert_eq!((tokens[3].span.col_start, tokens[3].span.col_end), (8, 8));
+    /// ```
+    ///
+    /// # Arguments
+    ///
+    /// * `second` - The character that completes the two-character operator.
+    /// * `two`    - The kind produced when `second` follows.
+    /// * `one`    - The kind produced for the lone current character.
+    fn match_two(&mut self, second: char, two: TokenKind, one: TokenKind) -> Token {
+        let col_start = self.current_column;
+        let src_start = self.current_source_pos;
+
+        let token_kind = if self.peek_char() == second {
+            self.advance();
+            two
+        } else {
+            one
+        };
+
+        Token {
+            literal: Literal::None,
+            lexeme: self.source[src_start..=self.current_source_pos]
+                .iter()
+                .collect(),
+            kind: token_kind,
+            span: self.get_span(
+                col_start,
+                self.current_column,
+                self.current_line,
+                self.current_line,
+            ),
+        }
+    }
+
+    /// Constructs the [`TokenKind::Eof`] token that terminates the stream.
+    ///
+    /// The span sits one column past the last real token, so errors such as
+    /// an expected `}` at end of input point just after the final lexeme
+    /// rather than at whatever line trailing whitespace left the lexer on.
+    /// An empty source places it at `1:1`.
+    ///
+    /// ```rust
+    /// use zast::{lexer::ZastLexer, parser::ZastParser};
+    ///
+    /// let tokens = ZastLexer::new("let x = 1;\n\n   ").tokenize().unwrap();
+    /// let eof = tokens.last().unwrap().span;
+    /// assert_eq!((eof.ln_start, eof.col_start), (1, 11));
+    ///
+    /// let src = "fn f(): void {\n    let x = 1;\n    x =";
+    /// let errors = ZastParser::from_source(src).unwrap_err();
+    /// assert!(errors.render_all(src)[0].starts_with("Error at: 3:8 |"));
+    /// ```
+    fn eof_token(&self) -> Token {
+        let span = match self.last_span {
+            Some(last) => {
+                self.get_span(last.col_end + 1, last.col_end + 1, last.ln_end, last.ln_end)
+            }
+            None => self.get_span(1, 1, 1, 1),
+        };
+
+        Token {
+            literal: Literal::None,
+            lexeme: String::from("END_OF_FILE"),
+            kind: TokenKind::Eof,
+            span,
+        }
+    }
+
     /// Constructs a single-character [`Token`] at the current source position.
     ///
     /// The span covers exactly the current column on the current line.
@@ -367,3 +1163,13 @@ impl ZastLexer {
         }
     }
 }
+
+/// Yields tokens one at a time via [`ZastLexer::next_token`], ending after the
+/// [`TokenKind::Eof`] token.
+impl Iterator for ZastLexer {
+    type Item = Token;
+
+    fn next(&mut self) -> Option<Token> {
+        self.next_token()
+    }
+}
@@ -1,15 +1,26 @@
+use std::collections::HashMap;
+
 /// All token types recognized by the Zast lexer.
 ///
 /// Variants are grouped by category:
 ///
 /// ```text
 /// Special      Illegal, Eof
-/// Literals     String, Identifier, Integer, Float
-/// Punctuation  Semicolon, Comma, Dot
-/// Delimiters   LeftParenthesis, RightParenthesis
-/// Operators    Plus, Minus, Multiply, Divide
-/// Keywords     Let
+/// Comments     LineComment, BlockComment
+/// Literals     String, Char, Identifier, Integer, Float
+/// Punctuation  Semicolon, Comma, Dot, Colon, Question
+/// Delimiters   LeftParenthesis, RightParenthesis, LeftBrace, RightBrace,
+///              LeftBracket, RightBracket
+/// Operators    Plus, Minus, Multiply, Divide, DoubleStar, Caret, Ampersand,
+///              Pipe, Bang, Equal, NotEqual, Less, LessEqual, Greater,
+///              GreaterEqual, LogicalAnd, LogicalOr, Shl, Shr
+/// Assignment   Assignment, PlusAssign, MinusAssign, MultiplyAssign, DivideAssign
+/// Keywords     Fn, Let, Const, Import, Struct, Enum, Return, If, Else, While,
+///              As
 /// ```
+///
+/// Every keyword variant is produced from its reserved word through
+/// [`TokenKind::KEYWORDS`].
 #[derive(Debug, PartialEq, Eq, Hash, Clone, Copy)]
 pub enum TokenKind {
     /// A character or sequence that does not match any known lexeme.
@@ -18,9 +29,23 @@ pub enum TokenKind {
     /// Marks the end of the token stream.
     Eof,
 
+    /// A line comment, e.g. `// note`. Only produced by
+    /// [`ZastLexer::with_comments_preserved`](crate::lexer::ZastLexer::with_comments_preserved).
+    /// The inner value excludes the leading `//`.
+    LineComment,
+
+    /// A block comment, e.g. `/* note */`, including any nested comments.
+    /// Only produced by
+    /// [`ZastLexer::with_comments_preserved`](crate::lexer::ZastLexer::with_comments_preserved).
+    /// The inner value excludes the outermost `/*` and `*/`.
+    BlockComment,
+
     /// A string literal, e.g. `"hello"`. The inner value excludes the quotes.
     String,
 
+    /// A char literal, e.g. `'a'` or `'\n'`. The inner value excludes the quotes.
+    Char,
+
     /// A user-defined name, e.g. `foo`, `_bar`, `myVar`.
     Identifier,
 
@@ -39,6 +64,9 @@ pub enum TokenKind {
     /// ':'
     Colon,
 
+    /// `?`
+    Question,
+
     /// '='
     Assignment,
 
@@ -57,9 +85,64 @@ pub enum TokenKind {
     /// `/`
     Divide,
 
-    /// `&`
+    /// `**` — exponentiation in infix position, e.g. `2 ** 10`. In prefix
+    /// and type position it stands for two `*`, e.g. `**pp` or `**u8`.
+    DoubleStar,
+
+    /// `^` — bitwise xor.
+    Caret,
+
+    /// `+=`
+    PlusAssign,
+
+    /// `-=`
+    MinusAssign,
+
+    /// `*=`
+    MultiplyAssign,
+
+    /// `/=`
+    DivideAssign,
+
+    /// `&` — address-of in prefix position, bitwise and in infix position.
     Ampersand,
 
+    /// `|` — bitwise or.
+    Pipe,
+
+    /// `!`
+    Bang,
+
+    /// `==`
+    Equal,
+
+    /// `!=`
+    NotEqual,
+
+    /// `<`
+    Less,
+
+    /// `<=`
+    LessEqual,
+
+    /// `>`
+    Greater,
+
+    /// `>=`
+    GreaterEqual,
+
+    /// `&&`
+    LogicalAnd,
+
+    /// `||`
+    LogicalOr,
+
+    /// `<<` — shift left.
+    Shl,
+
+    /// `>>` — shift right.
+    Shr,
+
     /// `(`
     LeftParenthesis,
 
@@ -72,14 +155,44 @@ pub enum TokenKind {
     /// `}`
     RightBrace,
 
-    /// 'fn' keyword - used for declaring a function
+    /// `[`
+    LeftBracket,
+
+    /// `]`
+    RightBracket,
+
+    /// `fn` keyword — introduces a function declaration.
     Fn,
 
     /// `let` keyword — introduces a mutable variable declaration.
     Let,
 
-    /// `const` keyword — introduces a mutable variable declaration.
+    /// `const` keyword — introduces an immutable variable declaration.
     Const,
+
+    /// `import` keyword — brings another module's top-level declarations into scope.
+    Import,
+
+    /// `struct` keyword — declares a named aggregate type.
+    Struct,
+
+    /// `enum` keyword — declares a type with a fixed set of named variants.
+    Enum,
+
+    /// `return` keyword — exits the enclosing function, optionally with a value.
+    Return,
+
+    /// `if` keyword — introduces a conditional statement.
+    If,
+
+    /// `else` keyword — introduces the alternative branch of an `if`.
+    Else,
+
+    /// `while` keyword — introduces a loop that runs while its condition holds.
+    While,
+
+    /// `as` keyword — converts a value to another type, e.g. `x as u8`.
+    As,
 }
 
 /// The literal value carried by a token, tagged by its kind.
@@ -91,6 +204,9 @@ pub enum Literal {
     /// A string literal value, e.g. the contents of `"hello"` excluding quotes.
     StringValue(String),
 
+    /// A single character value, e.g. the decoded contents of `'a'` or `'\n'`.
+    CharValue(char),
+
     /// A 64-bit signed integer value, e.g. `42`.
     IntegerValue(i64),
 
@@ -138,6 +254,14 @@ impl Literal {
         }
     }
 
+    /// Returns the inner char value if this is a [`Literal::CharValue`], otherwise `None`.
+    pub fn get_char(&self) -> Option<char> {
+        match self {
+            Self::CharValue(v) => Some(*v),
+            _ => None,
+        }
+    }
+
     /// Returns the inner integer value if this is a [`Literal::IntegerValue`], otherwise `None`.
     pub fn get_int(&self) -> Option<i64> {
         match self {
@@ -164,13 +288,67 @@ impl Literal {
 }
 
 impl TokenKind {
+    /// Returns `true` for [`TokenKind::LineComment`] and [`TokenKind::BlockComment`].
+    pub fn is_comment(&self) -> bool {
+        matches!(self, Self::LineComment | Self::BlockComment)
+    }
+
+    /// The reserved words of the language and the token kinds they lex as.
+    ///
+    /// [`ZastLexer::new`](crate::lexer::ZastLexer::new) uses exactly this set;
+    /// [`ZastLexer::with_keywords`](crate::lexer::ZastLexer::with_keywords)
+    /// can replace it.
+    ///
+    /// ```rust
+    /// use zast::lexer::tokens::{Span, Token, TokenKind};
+    ///
+    /// for (reserved, kind) in TokenKind::KEYWORDS {
+    ///     assert_eq!(Token::from_keyword(reserved, Span::default()).kind, kind);
+    /// }
+    ///
+    /// // every keyword the parser dispatches statements on can be lexed
+    /// for kind in [
+    ///     TokenKind::Let,
+    ///     TokenKind::Const,
+    ///     TokenKind::Fn,
+    ///     TokenKind::Import,
+    ///     TokenKind::Struct,
+    ///     TokenKind::Enum,
+    ///     TokenKind::Return,
+    ///     TokenKind::If,
+    ///     TokenKind::While,
+    /// ] {
+    ///     assert!(TokenKind::KEYWORDS.iter().any(|(_, keyword)| *keyword == kind));
+    /// }
+    /// ```
+    pub const KEYWORDS: [(&'static str, TokenKind); 11] = [
+        ("let", TokenKind::Let),
+        ("const", TokenKind::Const),
+        ("fn", TokenKind::Fn),
+        ("import", TokenKind::Import),
+        ("struct", TokenKind::Struct),
+        ("enum", TokenKind::Enum),
+        ("return", TokenKind::Return),
+        ("if", TokenKind::If),
+        ("else", TokenKind::Else),
+        ("while", TokenKind::While),
+        ("as", TokenKind::As),
+    ];
+
+    /// Returns the default keyword set as a map, ready to be extended or
+    /// edited and passed to
+    /// [`ZastLexer::with_keywords`](crate::lexer::ZastLexer::with_keywords).
+    pub fn default_keywords() -> HashMap<&'static str, TokenKind> {
+        Self::KEYWORDS.into_iter().collect()
+    }
+
     /// Returns `true` if this token kind carries a literal value.
     ///
     /// Literal tokens are those that have an associated [`Literal`] value
     /// rather than being purely structural (operators, keywords, punctuation).
     pub fn is_literal_value(&self) -> bool {
         match self {
-            Self::Identifier | Self::Integer | Self::String | Self::Float => true,
+            Self::Identifier | Self::Integer | Self::String | Self::Char | Self::Float => true,
             _ => false,
         }
     }
@@ -195,23 +373,78 @@ pub struct Token {
 }
 
 impl Token {
+    /// Returns the type suffix of a numeric literal token, e.g. `u8` for
+    /// `200u8` or `f32` for `3.0f32`. Returns `None` for unsuffixed literals
+    /// and for every other token kind.
+    pub fn literal_suffix(&self) -> Option<&str> {
+        if !matches!(self.kind, TokenKind::Integer | TokenKind::Float) {
+            return None;
+        }
+
+        let start = self.lexeme.find(|c: char| c.is_alphabetic())?;
+        Some(&self.lexeme[start..])
+    }
+
     /// Constructs a [`Token`] from a scanned identifier string.
     ///
-    /// If `keyword` matches a reserved word, the appropriate [`TokenKind`] is
-    /// assigned. Otherwise the token is classified as [`TokenKind::Identifier`].
+    /// If `keyword` matches one of the default [`TokenKind::KEYWORDS`], the
+    /// appropriate [`TokenKind`] is assigned. Otherwise the token is
+    /// classified as [`TokenKind::Identifier`].
     ///
     /// # Arguments
     ///
     /// * `keyword` - The raw scanned string to classify.
     /// * `span`    - The source location of the scanned string.
+    ///
+    /// ```rust
+    /// use zast::{
+    ///     ast::Stmt,
+    ///     lexer::tokens::{Span, Token, TokenKind},
+    ///     parser::ZastParser,
+    /// };
+    ///
+    /// assert_eq!(Token::from_keyword("fn", Span::default()).kind, TokenKind::Fn);
+    /// assert_eq!(Token::from_keyword("fnord", Span::default()).kind, TokenKind::Identifier);
+    ///
+    /// let program = ZastParser::from_source("fn f(): void { }").unwrap();
+    /// assert!(matches!(
+    ///     &program.body[0].node,
+    ///     Stmt::FunctionDeclaration { name, .. } if name == "f"
+    /// ));
+    /// ```
     pub fn from_keyword(keyword: &str, span: Span) -> Self {
-        let token_kind = match keyword {
-            "let" => TokenKind::Let,
-            "const" => TokenKind::Const,
-            "fn" => TokenKind::Fn,
-            _ => TokenKind::Identifier,
-        };
+        let token_kind = TokenKind::KEYWORDS
+            .iter()
+            .find(|(reserved, _)| *reserved == keyword)
+            .map_or(TokenKind::Identifier, |(_, kind)| *kind);
+
+        Self::from_keyword_kind(keyword, span, token_kind)
+    }
 
+    /// Constructs a [`Token`] from a scanned identifier string, classifying
+    /// it against a custom keyword set instead of the defaults.
+    ///
+    /// # Arguments
+    ///
+    /// * `keyword`  - The raw scanned string to classify.
+    /// * `span`     - The source location of the scanned string.
+    /// * `keywords` - The reserved words in effect and their token kinds.
+    pub fn from_keyword_in(
+        keyword: &str,
+        span: Span,
+        keywords: &HashMap<String, TokenKind>,
+    ) -> Self {
+        let token_kind = keywords
+            .get(keyword)
+            .copied()
+            .unwrap_or(TokenKind::Identifier);
+
+        Self::from_keyword_kind(keyword, span, token_kind)
+    }
+
+    /// Builds a keyword or identifier token once its kind is known. Only
+    /// identifiers carry a [`Literal::Identifier`] value.
+    fn from_keyword_kind(keyword: &str, span: Span, token_kind: TokenKind) -> Self {
         let literal = match token_kind {
             TokenKind::Identifier => Literal::Identifier(keyword.to_string()),
             _ => Literal::None,
@@ -241,12 +474,29 @@ impl Default for Token {
     }
 }
 
-/// A half-open source range `[ln_start:col_start, ln_end:col_end]` identifying
-/// where a token appears in the original source text.
+/// A source range `[ln_start:col_start, ln_end:col_end]` identifying where a
+/// token or syntax node appears in the original source text.
+///
+/// Both lines and columns are 1-based, and both ends are inclusive: `col_end`
+/// is the column of the last character covered, not one past it. A
+/// single-character token on line 3, column 7 has `ln_start = ln_end = 3` and
+/// `col_start = col_end = 7`. Spans merged with [`Span::merge`] keep the
+/// inclusive end of their last part.
+///
+/// ```rust
+/// use zast::{lexer::ZastLexer, parser::ZastParser};
 ///
-/// Both lines and columns are 1-based. A single-character token on line 3,
-/// column 7 would have `ln_start = ln_end = 3` and `col_start = col_end = 7`.
-#[derive(Default, Debug, Clone, Copy)]
+/// let tokens = ZastLexer::new("x <= 42").tokenize().unwrap();
+/// let columns: Vec<_> = tokens[..3]
+///     .iter()
+///     .map(|token| (token.span.col_start, token.span.col_end))
+///     .collect();
+/// assert_eq!(columns, [(1, 1), (3, 4), (6, 7)]);
+///
+/// let expr = ZastParser::new(tokens).parse_expression_entry().unwrap();
+/// assert_eq!((expr.span.col_start, expr.span.col_end), (1, 7));
+/// ```
+#[derive(Default, Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub struct Span {
     /// 1-based column of the first character of the token.
     pub col_start: usize,
@@ -262,22 +512,67 @@ pub struct Span {
 }
 
 impl Span {
-    pub fn format_span(span: Span) -> String {
-        let col: String;
-        let ln: String;
-
-        if span.ln_start == span.ln_end {
-            ln = format!("{}", span.ln_start);
-        } else {
-            ln = format!("{}-{}", span.ln_start, span.ln_end);
+    /// Returns the span covering everything from the start of `start` to the
+    /// end of `end`, e.g. an operator through its operand.
+    ///
+    /// `start` is expected to begin no later than `end` ends; the spans may
+    /// lie on different lines.
+    ///
+    /// ```rust
+    /// use zast::lexer::tokens::Span;
+    ///
+    /// let start = Span { ln_start: 1, ln_end: 1, col_start: 5, col_end: 8 };
+    /// let end = Span { ln_start: 3, ln_end: 3, col_start: 2, col_end: 4 };
+    ///
+    /// let merged = Span { ln_start: 1, ln_end: 3, col_start: 5, col_end: 4 };
+    /// assert_eq!(Span::merge(start, end), merged);
+    /// assert_eq!(start.to(&end), merged);
+    /// ```
+    pub fn merge(start: Span, end: Span) -> Span {
+        Span {
+            col_start: start.col_start,
+            col_end: end.col_end,
+            ln_start: start.ln_start,
+            ln_end: end.ln_end,
         }
+    }
 
-        if span.col_start == span.col_end {
-            col = format!("{}", span.col_start);
+    /// Returns the span from the start of `self` to the end of `other`.
+    /// Equivalent to `Span::merge(*self, *other)`.
+    pub fn to(&self, other: &Span) -> Span {
+        Span::merge(*self, *other)
+    }
+
+    /// Formats a span as `line:column` for use in diagnostics.
+    ///
+    /// Ranges are collapsed when both ends coincide, so a single-character
+    /// token at line 3, column 7 renders as `3:7`, while a multi-line span
+    /// renders as `ln_start:col_start-ln_end:col_end`.
+    ///
+    /// ```rust
+    /// use zast::lexer::tokens::Span;
+    ///
+    /// let token = Span { ln_start: 3, ln_end: 3, col_start: 7, col_end: 7 };
+    /// assert_eq!(Span::format_span(token), "3:7");
+    ///
+    /// let word = Span { col_end: 9, ..token };
+    /// assert_eq!(Span::format_span(word), "3:7-9");
+    ///
+    /// let block = Span { ln_end: 5, col_end: 1, ..token };
+    /// assert_eq!(Span::format_span(block), "3:7-5:1");
+    /// ```
+    pub fn format_span(span: Span) -> String {
+        if span.ln_start == span.ln_end {
+            if span.col_start == span.col_end {
+                format!("{}:{}", span.ln_start, span.col_start)
+            } else {
+                format!("{}:{}-{}", span.ln_start, span.col_start, span.col_end)
+            }
         } else {
-            col = format!("{}-{}", span.col_start, span.col_end);
+            format!(
+                "{}:{}-{}:{}",
+                span.ln_start, span.col_start, span.ln_end, span.col_end
+            )
         }
-
-        format!("{}:{}", col, ln)
     }
 }
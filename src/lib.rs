@@ -1,7 +1,11 @@
 pub mod ast;
+pub mod config;
 pub mod error_handler;
 pub mod lexer;
 pub mod sema;
 pub mod zast_ir;
 pub mod parser;
+pub mod pipeline;
+pub mod prelude;
+pub mod source_map;
 pub mod types;
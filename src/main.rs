@@ -1,4 +1,4 @@
-use zast::{lexer::ZastLexer, parser::ZastParser, sema::ZastSemanticAnalyzer};
+use zast::prelude::*;
 
 fn main() {
     let src = r#"
@@ -6,23 +6,10 @@ fn main() {
         
     }
 "#;
-    let mut lexer = ZastLexer::new(src);
-    match lexer.tokenize() {
-        Ok(toks) => {
-            let mut parser = ZastParser::new(toks);
-            match parser.parse_program() {
-                Ok(ast) => {
-                    let mut sema = ZastSemanticAnalyzer::new();
-                    match sema.analyze(ast) {
-                        Ok(()) => println!("{:#?}", sema),
-                        Err(e) => e.report_all_errors(),
-                    };
-                }
-                Err(err) => err.report_all_errors(),
-            };
-        }
-        Err(err) => {
-            err.report_all_errors();
-        }
-    };
+    let compilation = ZastPipeline::new().compile(src);
+    compilation.errors.report_all_errors(src);
+
+    if compilation.is_success() {
+        println!("{:#?}", compilation.program);
+    }
 }
@@ -3,15 +3,22 @@ pub mod precedence_table;
 pub mod statements;
 pub mod types;
 
-use std::{collections::HashMap, mem};
+use std::{
+    collections::{HashMap, HashSet},
+    mem,
+};
 
 use crate::{
     ast::{Expression, Statement, ZastProgram},
+    config::ZastConfig,
     error_handler::{
         ZastErrorCollector,
         zast_errors::{Expected, ZastError},
     },
-    lexer::tokens::{Token, TokenKind},
+    lexer::{
+        ZastLexer,
+        tokens::{Span, Token, TokenKind},
+    },
     parser::precedence_table::Precedence,
 };
 
@@ -51,10 +58,15 @@ type StmtParseFn = fn(&mut ZastParser) -> Option<Statement>;
 /// # Example
 ///
 /// ```rust
+/// use zast::{lexer::ZastLexer, parser::ZastParser};
+///
+/// let src = "fn main(): void {}";
+/// let tokens = ZastLexer::new(src).tokenize().unwrap();
+///
 /// let mut parser = ZastParser::new(tokens);
 /// match parser.parse_program() {
 ///     Ok(program) => println!("{:#?}", program),
-///     Err(errors) => errors.iter().for_each(|e| eprintln!("Error: {e}")),
+///     Err(errors) => errors.report_all_errors(src),
 /// }
 /// ```
 pub struct ZastParser {
@@ -75,6 +87,14 @@ pub struct ZastParser {
 
     /// Lookup table mapping token kinds to statement parse functions.
     stmt_lookup: HashMap<TokenKind, StmtParseFn>,
+
+    /// Kinds and spans of the `(`, `{`, and `[` tokens consumed so far that
+    /// have not been closed yet, innermost last.
+    open_delimiters: Vec<(TokenKind, Span)>,
+
+    /// Names of the enums declared so far, so that `Color.Red` parses as an
+    /// enum variant rather than a field access.
+    enum_names: HashSet<String>,
 }
 
 impl ZastParser {
@@ -85,19 +105,48 @@ impl ZastParser {
     ///
     /// * `tokens` - The token stream produced by [`ZastLexer::tokenize`].
     pub fn new(tokens: Vec<Token>) -> Self {
+        Self::with_config(tokens, &ZastConfig::default())
+    }
+
+    /// Creates a new `ZastParser` that honors the parsing options in
+    /// `config`, such as [`ZastConfig::max_errors`]. Parsing stops early once
+    /// that many errors have been collected.
+    ///
+    /// # Arguments
+    ///
+    /// * `tokens` - The token stream produced by [`ZastLexer::tokenize`].
+    /// * `config` - The pipeline configuration.
+    pub fn with_config(mut tokens: Vec<Token>, config: &ZastConfig) -> Self {
+        // comment tokens only exist for tools that preserve them
+        tokens.retain(|token| !token.kind.is_comment());
+
+        // every position must resolve to a token, even for an empty stream
+        if tokens
+            .last()
+            .is_none_or(|token| token.kind != TokenKind::Eof)
+        {
+            tokens.push(Token::default());
+        }
+
         let mut parser = Self {
             tokens,
             current_token_ptr: 0,
-            errors: ZastErrorCollector::new(),
+            errors: ZastErrorCollector::from_config(config),
             nud_lookup: HashMap::new(),
             led_lookup: HashMap::new(),
             stmt_lookup: HashMap::new(),
+            open_delimiters: Vec::new(),
+            enum_names: HashSet::new(),
         };
 
         parser.register_nud(TokenKind::Multiply, ZastParser::parse_deref_expr);
+        parser.register_nud(TokenKind::DoubleStar, ZastParser::parse_deref_expr);
         parser.register_nud(TokenKind::Ampersand, ZastParser::parse_addr_expr);
+        parser.register_nud(TokenKind::Minus, ZastParser::parse_negate_expr);
         parser.register_nud(TokenKind::Integer, ZastParser::parse_integer_literal);
         parser.register_nud(TokenKind::Float, ZastParser::parse_float_literal);
+        parser.register_nud(TokenKind::Char, ZastParser::parse_char_literal);
+        parser.register_nud(TokenKind::String, ZastParser::parse_string_literal);
         parser.register_nud(TokenKind::Identifier, ZastParser::parse_identifier_literal);
         parser.register_nud(
             TokenKind::LeftParenthesis,
@@ -108,14 +157,65 @@ impl ZastParser {
         parser.register_led(TokenKind::Minus, ZastParser::parse_binary_expr);
         parser.register_led(TokenKind::Divide, ZastParser::parse_binary_expr);
         parser.register_led(TokenKind::Multiply, ZastParser::parse_binary_expr);
+        parser.register_led(TokenKind::DoubleStar, ZastParser::parse_binary_expr);
+        parser.register_led(TokenKind::Caret, ZastParser::parse_binary_expr);
+        parser.register_led(TokenKind::Equal, ZastParser::parse_binary_expr);
+        parser.register_led(TokenKind::NotEqual, ZastParser::parse_binary_expr);
+        parser.register_led(TokenKind::Less, ZastParser::parse_binary_expr);
+        parser.register_led(TokenKind::LessEqual, ZastParser::parse_binary_expr);
+        parser.register_led(TokenKind::Greater, ZastParser::parse_binary_expr);
+        parser.register_led(TokenKind::GreaterEqual, ZastParser::parse_binary_expr);
+        parser.register_led(TokenKind::LogicalAnd, ZastParser::parse_binary_expr);
+        parser.register_led(TokenKind::LogicalOr, ZastParser::parse_binary_expr);
+        parser.register_led(TokenKind::Ampersand, ZastParser::parse_binary_expr);
+        parser.register_led(TokenKind::Pipe, ZastParser::parse_binary_expr);
+        parser.register_led(TokenKind::Shl, ZastParser::parse_binary_expr);
+        parser.register_led(TokenKind::Shr, ZastParser::parse_binary_expr);
+        parser.register_led(TokenKind::LeftBracket, ZastParser::parse_index_expr);
+        parser.register_led(TokenKind::Question, ZastParser::parse_ternary_expr);
+        parser.register_led(TokenKind::Assignment, ZastParser::parse_assignment_expr);
+        parser.register_led(TokenKind::PlusAssign, ZastParser::parse_assignment_expr);
+        parser.register_led(TokenKind::MinusAssign, ZastParser::parse_assignment_expr);
+        parser.register_led(TokenKind::MultiplyAssign, ZastParser::parse_assignment_expr);
+        parser.register_led(TokenKind::DivideAssign, ZastParser::parse_assignment_expr);
+        parser.register_led(TokenKind::Dot, ZastParser::parse_field_access_expr);
+        parser.register_led(TokenKind::LeftParenthesis, ZastParser::parse_call_expr);
+        parser.register_led(TokenKind::As, ZastParser::parse_cast_expr);
 
         parser.register_stmt(TokenKind::Let, ZastParser::parse_variable_declaration);
         parser.register_stmt(TokenKind::Const, ZastParser::parse_variable_declaration);
         parser.register_stmt(TokenKind::Fn, ZastParser::parse_function_declaration);
+        parser.register_stmt(TokenKind::Import, ZastParser::parse_import_statement);
+        parser.register_stmt(TokenKind::Struct, ZastParser::parse_struct_declaration);
+        parser.register_stmt(TokenKind::Enum, ZastParser::parse_enum_declaration);
+        parser.register_stmt(TokenKind::Return, ZastParser::parse_return_statement);
+        parser.register_stmt(TokenKind::LeftBrace, ZastParser::parse_block_statement);
+        parser.register_stmt(TokenKind::If, ZastParser::parse_if_statement);
+        parser.register_stmt(TokenKind::While, ZastParser::parse_while_statement);
 
         parser
     }
 
+    /// Lexes and parses `src` in one call, e.g. for tests and tools that only
+    /// need the AST.
+    ///
+    /// Lexical errors stop before parsing, since the parser would only report
+    /// cascades of the same problems; they are returned in the same
+    /// [`ZastErrorCollector`] the parser would use.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use zast::parser::ZastParser;
+    ///
+    /// let program = ZastParser::from_source("fn f(): void {}").unwrap();
+    /// assert_eq!(program.body.len(), 1);
+    /// ```
+    pub fn from_source(src: &str) -> Result<ZastProgram, ZastErrorCollector> {
+        let tokens = ZastLexer::new(src).tokenize()?;
+        Self::new(tokens).parse_program()
+    }
+
     /// Registers a NUD (prefix) parse function for the given token kind.
     pub fn register_nud(&mut self, token_kind: TokenKind, nud_fn: NUDParseFn) {
         self.nud_lookup.insert(token_kind, nud_fn);
@@ -135,7 +235,8 @@ impl ZastParser {
     ///
     /// Iterates over all tokens, attempting to parse each top-level statement.
     /// If a statement parse fails, [`ZastParser::sync_tokens`] is called to
-    /// recover and continue parsing remaining statements.
+    /// recover and continue parsing remaining statements. Parsing bails out
+    /// early once the error collector reaches its limit.
     ///
     /// # Returns
     ///
@@ -144,14 +245,19 @@ impl ZastParser {
     pub fn parse_program(&mut self) -> Result<ZastProgram, ZastErrorCollector> {
         let mut body = Vec::new();
         while !self.is_at_eof() {
-            let node = self.try_parse_stmt();
-            if node.is_none() {
-                self.sync_tokens();
-            } else {
-                body.push(node.unwrap());
+            if self.errors.is_full() {
+                self.errors.mark_truncated();
+                break;
+            }
+
+            match self.try_parse_stmt() {
+                Some(node) => body.push(node),
+                None => self.sync_tokens(),
             }
         }
 
+        self.report_unclosed_delimiters();
+
         if self.errors.has_errors() {
             Err(mem::take(&mut self.errors))
         } else {
@@ -159,11 +265,106 @@ impl ZastParser {
         }
     }
 
+    /// Parses exactly one statement, e.g. `let x: i32 = 1;`, for REPLs and
+    /// other tools that embed the parser.
+    ///
+    /// Any token left over after the statement, other than
+    /// [`TokenKind::Eof`], is reported as an [`ZastError::UnexpectedToken`].
+    ///
+    /// # Returns
+    ///
+    /// - `Ok(Statement)` if the input is a single valid statement.
+    /// - `Err(ZastErrorCollector)` containing all accumulated errors otherwise.
+    ///
+    /// ```rust
+    /// use zast::{ast::Stmt, lexer::ZastLexer, parser::ZastParser};
+    ///
+    /// let parser = |src: &str| ZastParser::new(ZastLexer::new(src).tokenize().unwrap());
+    ///
+    /// let stmt = parser("let x: i32 = 1;").parse_single_statement().unwrap();
+    /// assert!(matches!(stmt.node, Stmt::VariableDeclaration { mutable: true, .. }));
+    /// assert!(parser("let x: i32 = 1; x;").parse_single_statement().is_err());
+    /// ```
+    pub fn parse_single_statement(&mut self) -> Result<Statement, ZastErrorCollector> {
+        let stmt = self.try_parse_stmt();
+        self.finish_single(stmt)
+    }
+
+    /// Parses exactly one expression, e.g. `1 + 2`, with no trailing `;`.
+    ///
+    /// Any token left over after the expression, other than
+    /// [`TokenKind::Eof`], is reported as an [`ZastError::UnexpectedToken`].
+    ///
+    /// # Returns
+    ///
+    /// - `Ok(Expression)` if the input is a single valid expression.
+    /// - `Err(ZastErrorCollector)` containing all accumulated errors otherwise.
+    ///
+    /// ```rust
+    /// use zast::{ast::Expr, lexer::ZastLexer, parser::ZastParser};
+    ///
+    /// let parser = |src: &str| ZastParser::new(ZastLexer::new(src).tokenize().unwrap());
+    ///
+    /// let expr = parser("1 + 2").parse_expression_entry().unwrap();
+    /// assert!(matches!(expr.node, Expr::BinaryExpression { .. }));
+    /// assert!(parser("1 + 2 3").parse_expression_entry().is_err());
+    /// ```
+    pub fn parse_expression_entry(&mut self) -> Result<Expression, ZastErrorCollector> {
+        let expr = self.try_parse_expr(Precedence::Default);
+        self.finish_single(expr)
+    }
+
+    /// Shared tail of the single-item entry points: rejects trailing tokens
+    /// and converts the outcome into a `Result`.
+    fn finish_single<T>(&mut self, node: Option<T>) -> Result<T, ZastErrorCollector> {
+        self.report_unclosed_delimiters();
+
+        if node.is_some() && !self.is_at_eof() {
+            let tok = self.current_token();
+            self.errors.add_error(ZastError::UnexpectedToken {
+                span: tok.span,
+                token_kind: tok.kind,
+            });
+        }
+
+        match node {
+            Some(node) if !self.errors.has_errors() => Ok(node),
+            _ => Err(mem::take(&mut self.errors)),
+        }
+    }
+
     /// Wraps the parsed statement list into a [`ZastProgram`].
     fn finish(&self, body: Vec<Statement>) -> ZastProgram {
         ZastProgram { body }
     }
 
+    /// Reports every delimiter still open once parsing has stopped as a
+    /// [`ZastError::UnclosedDelimiter`] pointing at its opening token.
+    ///
+    /// Only applies when the input ran out: the closing tokens expected at
+    /// [`TokenKind::Eof`] are not reported separately, see
+    /// [`ZastParser::check`].
+    ///
+    /// ```rust
+    /// use zast::parser::ZastParser;
+    ///
+    /// let errors = ZastParser::from_source("fn f(): void {").unwrap_err();
+    /// let [unclosed] = errors.errors() else { unreachable!() };
+    ///
+    /// assert_eq!(unclosed.get_error_code(), "E0030");
+    /// assert_eq!(unclosed.get_error_msg(), "Unclosed delimiter '{'");
+    /// assert_eq!((unclosed.get_span().ln_start, unclosed.get_span().col_start), (1, 14));
+    /// ```
+    fn report_unclosed_delimiters(&mut self) {
+        if !self.is_at_eof() {
+            return;
+        }
+
+        for (delimiter, span) in mem::take(&mut self.open_delimiters) {
+            self.throw_error(ZastError::UnclosedDelimiter { span, delimiter });
+        }
+    }
+
     /// Adds an error to the error collector.
     pub(crate) fn throw_error(&mut self, err: ZastError) {
         self.errors.add_error(err);
@@ -178,12 +379,48 @@ impl ZastParser {
     /// Recovery points are:
     /// - `;` at depth 0 — end of a statement
     /// - `)` or `}` at depth 0 — end of a block or parameter list
+    /// - a statement keyword such as `let`, `fn`, or `return` at depth 0 —
+    ///   the start of the next statement, which is left unconsumed so it is
+    ///   parsed normally
     /// - [`TokenKind::Eof`] — end of input
+    ///
+    /// ```rust
+    /// use zast::parser::ZastParser;
+    ///
+    /// let messages = |src: &str| {
+    ///     let errors = ZastParser::from_source(src).unwrap_err();
+    ///     errors.errors().iter().map(|e| e.get_error_msg()).collect::<Vec<_>>()
+    /// };
+    ///
+    /// // recovery stops at `let`, so the declaration after the broken
+    /// // expression parses cleanly, and its own errors are still reported
+    /// assert_eq!(
+    ///     messages("const a: i32 = 1 * / 2 let x: i32 = 2;"),
+    ///     ["Unexpected token found 'Divide'"]
+    /// );
+    /// assert_eq!(
+    ///     messages("const a: i32 = 1 * / 2 let x: i32 = ;"),
+    ///     ["Unexpected token found 'Divide'", "Unexpected token found 'Semicolon'"]
+    /// );
+    /// ```
     fn sync_tokens(&mut self) {
         let mut depth = 0;
 
         while !self.is_at_eof() {
             match self.current_token_kind() {
+                TokenKind::Let
+                | TokenKind::Const
+                | TokenKind::Fn
+                | TokenKind::If
+                | TokenKind::While
+                | TokenKind::Return
+                | TokenKind::Struct
+                | TokenKind::Enum
+                | TokenKind::Import
+                    if depth == 0 =>
+                {
+                    return;
+                }
                 TokenKind::LeftParenthesis | TokenKind::LeftBrace => {
                     depth += 1;
                     self.advance();
@@ -215,6 +452,12 @@ impl ZastParser {
         &self.tokens[self.current_token_ptr]
     }
 
+    /// Returns a reference to the most recently consumed token, or the
+    /// current token if nothing has been consumed yet.
+    pub(crate) fn previous_token(&self) -> &Token {
+        &self.tokens[self.current_token_ptr.saturating_sub(1)]
+    }
+
     /// Returns a reference to the token immediately following the current token.
     ///
     /// Equivalent to `peek_at(1)`.
@@ -256,12 +499,45 @@ impl ZastParser {
     /// Advances the parser to the next token.
     ///
     /// Has no effect if the parser is already at the last token in the stream.
+    /// Consuming an opening delimiter records it as open; consuming the
+    /// matching closing delimiter closes it again.
     pub(crate) fn advance(&mut self) {
         if self.current_token_ptr + 1 < self.tokens.len() {
+            self.track_delimiter();
             self.current_token_ptr += 1;
         }
     }
 
+    /// Updates [`ZastParser::open_delimiters`] for the current token before
+    /// it is consumed. A closing delimiter closes the innermost open
+    /// delimiter of its kind, abandoning any opened inside it, whose missing
+    /// closers have already been reported. A closer with no opener is ignored.
+    fn track_delimiter(&mut self) {
+        let tok = self.current_token();
+        let (kind, span) = (tok.kind, tok.span);
+
+        match kind {
+            TokenKind::LeftParenthesis | TokenKind::LeftBrace | TokenKind::LeftBracket => {
+                self.open_delimiters.push((kind, span));
+            }
+            TokenKind::RightParenthesis | TokenKind::RightBrace | TokenKind::RightBracket => {
+                let opener = self.open_delimiters.iter().rposition(|(open, _)| {
+                    matches!(
+                        (open, kind),
+                        (TokenKind::LeftParenthesis, TokenKind::RightParenthesis)
+                            | (TokenKind::LeftBrace, TokenKind::RightBrace)
+                            | (TokenKind::LeftBracket, TokenKind::RightBracket)
+                    )
+                });
+
+                if let Some(opener) = opener {
+                    self.open_delimiters.truncate(opener);
+                }
+            }
+            _ => {}
+        }
+    }
+
     /// Returns `true` if the current token is [`TokenKind::Eof`].
     fn is_at_eof(&self) -> bool {
         self.current_token_kind() == TokenKind::Eof
@@ -285,26 +561,46 @@ impl ZastParser {
         }
     }
 
+    /// Returns `true` if the current token matches any of the expected
+    /// tokens, without consuming it or reporting anything.
+    ///
+    /// Use this to look ahead where a mismatch is a normal outcome, e.g. to
+    /// see whether a parameter list is empty; use [`ZastParser::check`] when
+    /// a mismatch is an error.
+    ///
+    /// # Arguments
+    ///
+    /// * `expected` - A list of acceptable [`Expected`] tokens or concepts.
+    pub(crate) fn matches(&self, expected: &[Expected]) -> bool {
+        let tok_kind = self.current_token_kind();
+
+        expected.iter().any(|e| match e {
+            Expected::Token(kind) => tok_kind == *kind,
+            Expected::Concept(_) => false,
+        })
+    }
+
     /// Checks whether the current token matches any of the expected tokens
     /// without consuming it.
     ///
-    /// Emits an [`ZastError::ExpectedToken`] error if no match is found.
+    /// Emits an [`ZastError::ExpectedToken`] error if no match is found,
+    /// unless the input ended inside an open delimiter; that case is reported
+    /// once as a [`ZastError::UnclosedDelimiter`] when parsing stops.
     /// Unlike [`ZastParser::expect`], this method never advances the token pointer.
     ///
     /// # Arguments
     ///
     /// * `expected` - A list of acceptable [`Expected`] tokens or concepts.
     pub(crate) fn check(&mut self, expected: Vec<Expected>) -> bool {
+        if self.matches(&expected) {
+            return true;
+        }
+
         let tok = self.current_token();
         let tok_kind = self.current_token_kind();
 
-        let matches = expected.iter().any(|e| match e {
-            Expected::Token(kind) => tok_kind == *kind,
-            Expected::Concept(_) => false,
-        });
-
-        if matches {
-            return true;
+        if tok_kind == TokenKind::Eof && !self.open_delimiters.is_empty() {
+            return false;
         }
 
         self.errors.add_error(ZastError::ExpectedToken {
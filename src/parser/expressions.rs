@@ -1,8 +1,9 @@
 use crate::{
-    ast::{Expr, Expression},
+    ast::{Argument, Expr, Expression},
     error_handler::zast_errors::{Expected, ZastError},
     lexer::tokens::{Span, TokenKind},
     parser::{ZastParser, precedence_table::Precedence},
+    types::annotated_type::AnnotatedType,
 };
 
 impl ZastParser {
@@ -57,18 +58,35 @@ impl ZastParser {
     /// Parses a unary dereference expression, e.g. `*ptr`.
     ///
     /// Consumes the `*` token and parses the operand at [`Precedence::Unary`]
-    /// so that only the immediate right-hand primary is consumed.
+    /// so that only the immediate right-hand primary is consumed. A `**`
+    /// token dereferences twice, so `**pp` is `*(*pp)`. Exponentiation binds
+    /// tighter than any prefix operator, so `**pp ** 2` is `**(pp ** 2)`.
+    ///
+    /// ```rust
+    /// use zast::pipeline::ZastPipeline;
+    ///
+    /// let src = "fn f(pp: **i32): i32 { return (**pp) ** 2; }";
+    /// assert!(ZastPipeline::new().compile(src).is_success());
+    ///
+    /// let src = "fn f(pp: **i32): i32 { return **pp ** 2; }";
+    /// let errors = ZastPipeline::new().compile(src).errors;
+    /// assert_eq!(errors.errors()[0].get_error_code(), "E0024");
+    /// ```
     pub fn parse_deref_expr(&mut self) -> Option<Expression> {
         let op_span = self.current_token().span;
-        self.advance(); // eat '*'
+        let double = self.current_token_kind() == TokenKind::DoubleStar;
+        self.advance(); // eat '*' or '**'
 
-        let operand = self.try_parse_expr(Precedence::Unary)?;
-        let full_span = Span {
-            col_start: op_span.col_start,
-            col_end: operand.span.col_end,
-            ln_start: op_span.ln_start,
-            ln_end: operand.span.ln_end,
-        };
+        let mut operand = self.try_parse_expr(Precedence::Unary)?;
+        if double {
+            let inner_span = Span {
+                col_start: op_span.col_start + 1,
+                ..op_span
+            };
+            let span = Span::merge(inner_span, operand.span);
+            operand = Expr::Dereference(Box::new(operand)).spanned(span);
+        }
+        let full_span = Span::merge(op_span, operand.span);
 
         Some(Expr::Dereference(Box::new(operand)).spanned(full_span))
     }
@@ -82,17 +100,27 @@ impl ZastParser {
         self.advance(); // eat '&'
 
         let operand = self.try_parse_expr(Precedence::Unary)?;
-        let full_span = Span {
-            col_start: op_span.col_start,
-            col_end: operand.span.col_end,
-            ln_start: op_span.ln_start,
-            ln_end: operand.span.ln_end,
-        };
+        let full_span = Span::merge(op_span, operand.span);
 
         Some(Expr::Address(Box::new(operand)).spanned(full_span))
     }
 
-    /// Parses an integer literal token into an [`Expr::IntegerLiteral`].
+    /// Parses a unary negation expression, e.g. `-x`, `-1`.
+    ///
+    /// Consumes the `-` token and parses the operand at [`Precedence::Unary`]
+    /// so that only the immediate right-hand primary is consumed.
+    pub fn parse_negate_expr(&mut self) -> Option<Expression> {
+        let op_span = self.current_token().span;
+        self.advance(); // eat '-'
+
+        let operand = self.try_parse_expr(Precedence::Unary)?;
+        let full_span = Span::merge(op_span, operand.span);
+
+        Some(Expr::Negate(Box::new(operand)).spanned(full_span))
+    }
+
+    /// Parses an integer literal token into an [`Expr::IntegerLiteral`],
+    /// keeping its type suffix (e.g. `u8` in `200u8`) if it has one.
     ///
     /// # Panics
     ///
@@ -101,12 +129,16 @@ impl ZastParser {
     /// dispatched for [`TokenKind::Integer`] tokens.
     pub fn parse_integer_literal(&mut self) -> Option<Expression> {
         let span = self.current_token().span;
-        let expr = Expr::IntegerLiteral(self.current_token().literal.get_int().unwrap());
+        let expr = Expr::IntegerLiteral {
+            value: self.current_token().literal.get_int().unwrap(),
+            suffix: self.literal_suffix(),
+        };
         self.advance();
         Some(expr.spanned(span))
     }
 
-    /// Parses a float literal token into an [`Expr::FloatLiteral`].
+    /// Parses a float literal token into an [`Expr::FloatLiteral`], keeping
+    /// its type suffix (e.g. `f32` in `3.0f32`) if it has one.
     ///
     /// # Panics
     ///
@@ -115,7 +147,56 @@ impl ZastParser {
     /// dispatched for [`TokenKind::Float`] tokens.
     pub fn parse_float_literal(&mut self) -> Option<Expression> {
         let span = self.current_token().span;
-        let expr = Expr::FloatLiteral(self.current_token().literal.get_float().unwrap());
+        let expr = Expr::FloatLiteral {
+            value: self.current_token().literal.get_float().unwrap(),
+            suffix: self.literal_suffix(),
+        };
+        self.advance();
+        Some(expr.spanned(span))
+    }
+
+    /// Returns the current numeric literal's type suffix as a primitive type
+    /// annotation, or `None` if it is unsuffixed.
+    fn literal_suffix(&self) -> Option<AnnotatedType> {
+        let suffix = self.current_token().literal_suffix()?;
+        Some(AnnotatedType::Primitive(suffix.to_string()))
+    }
+
+    /// Parses a char literal token into an [`Expr::CharLiteral`].
+    ///
+    /// # Panics
+    ///
+    /// Panics if the current token's literal is not a [`Literal::CharValue`].
+    /// This should not occur under normal operation since this function is only
+    /// dispatched for [`TokenKind::Char`] tokens.
+    pub fn parse_char_literal(&mut self) -> Option<Expression> {
+        let span = self.current_token().span;
+        let expr = Expr::CharLiteral(self.current_token().literal.get_char().unwrap());
+        self.advance();
+        Some(expr.spanned(span))
+    }
+
+    /// Parses a string literal token into an [`Expr::StringLiteral`], holding
+    /// its decoded text without the quotes. Semantic analysis types it as a
+    /// C-style `*u8`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use zast::pipeline::ZastPipeline;
+    ///
+    /// assert!(ZastPipeline::new().compile("const s: *u8 = \"hi\";").is_success());
+    /// assert!(!ZastPipeline::new().compile("const n: i32 = \"hi\";").is_success());
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if the current token's literal is not a [`Literal::StringValue`].
+    /// This should not occur under normal operation since this function is only
+    /// dispatched for [`TokenKind::String`] tokens.
+    pub fn parse_string_literal(&mut self) -> Option<Expression> {
+        let span = self.current_token().span;
+        let expr = Expr::StringLiteral(self.current_token().literal.get_string().unwrap());
         self.advance();
         Some(expr.spanned(span))
     }
@@ -134,11 +215,51 @@ impl ZastParser {
         Some(expr.spanned(span))
     }
 
-    /// Parses a binary infix expression, e.g. `a + b`, `x * y`.
+    /// Parses the right operand of the infix operator `op`, which has just
+    /// been consumed, at [`Precedence::right_operand`].
+    ///
+    /// Every LED function with an expression on its right goes through here,
+    /// so associativity is decided by [`Precedence::associativity`] alone:
+    ///
+    /// ```rust
+    /// use zast::{ast::Expr, lexer::ZastLexer, parser::ZastParser};
+    ///
+    /// let parse = |src: &str| {
+    ///     let tokens = ZastLexer::new(src).tokenize().unwrap();
+    ///     ZastParser::new(tokens).parse_expression_entry().unwrap().node
+    /// };
+    ///
+    /// // (a - b) - c
+    /// let Expr::BinaryExpression { left, .. } = parse("a - b - c") else { panic!() };
+    /// assert!(matches!(left.node, Expr::BinaryExpression { .. }));
+    ///
+    /// // a = (b = c)
+    /// let Expr::Assignment { value, .. } = parse("a = b = c") else { panic!() };
+    /// assert!(matches!(value.node, Expr::Assignment { .. }));
+    /// ```
+    pub(crate) fn parse_right_operand(&mut self, op: TokenKind) -> Option<Expression> {
+        self.try_parse_expr(Precedence::right_operand(op))
+    }
+
+    /// Parses a binary infix expression, e.g. `a + b`, `x * y`, `2 ** 3`.
     ///
     /// Called as a LED function with the left-hand expression already parsed.
-    /// Consumes the operator token and recursively parses the right-hand side
-    /// at the operator's own precedence level.
+    /// Consumes the operator token and parses the right-hand side via
+    /// [`ZastParser::parse_right_operand`], so `a - b - c` nests as
+    /// `(a - b) - c` while the right-associative `2 ** 3 ** 2` nests as
+    /// `2 ** (3 ** 2)`.
+    ///
+    /// ```rust
+    /// use zast::{parser::ZastParser, pipeline::ZastPipeline};
+    ///
+    /// let src = "fn f(): i32 { 2 ** 3 ** 2 }";
+    /// let dump = ZastParser::from_source(src).unwrap().dump(src);
+    /// assert!(dump.contains("BinaryExpression DoubleStar `2 ** 3 ** 2`"));
+    /// assert!(dump.contains("BinaryExpression DoubleStar `3 ** 2`"));
+    ///
+    /// // an integer raised to an integer is an integer of the same type
+    /// assert!(ZastPipeline::new().compile(src).is_success());
+    /// ```
     ///
     /// # Arguments
     ///
@@ -148,16 +269,10 @@ impl ZastParser {
         let left_span = left.span;
         self.advance(); // eat operator
 
-        let right =
-            self.try_parse_expr(Precedence::get_precedence(op).unwrap_or(Precedence::Default))?;
+        let right = self.parse_right_operand(op)?;
         let right_span = right.span;
 
-        let full_span = Span {
-            ln_start: left_span.ln_start,
-            ln_end: right_span.ln_end,
-            col_start: left_span.col_start,
-            col_end: right_span.col_end,
-        };
+        let full_span = Span::merge(left_span, right_span);
 
         Some(
             Expr::BinaryExpression {
@@ -169,6 +284,357 @@ impl ZastParser {
         )
     }
 
+    /// Parses an assignment expression, e.g. `x = 5`, `p.x += 1`.
+    ///
+    /// Called as a LED function with the assignment target already parsed.
+    /// Assignment is right-associative: the value is parsed via
+    /// [`ZastParser::parse_right_operand`], so it may itself be an assignment
+    /// and `a = b = c` nests as `a = (b = c)`:
+    ///
+    /// ```rust
+    /// use zast::parser::ZastParser;
+    ///
+    /// let src = "fn f(a: i32, b: i32, c: i32): void { a = b = c; }";
+    /// let dump = ZastParser::from_source(src).unwrap().dump(src);
+    ///
+    /// assert!(dump.contains("Assignment `a = b = c`"));
+    /// assert!(dump.contains("Assignment `b = c`"));
+    /// ```
+    ///
+    /// Compound operators are desugared into a plain assignment whose value is
+    /// the matching binary expression, so `x += 1` produces the same tree as
+    /// `x = x + 1`.
+    ///
+    /// Whether the target is actually assignable is left to semantic analysis.
+    ///
+    /// ```rust
+    /// use zast::{
+    ///     ast::Expr,
+    ///     lexer::{ZastLexer, tokens::TokenKind},
+    ///     parser::ZastParser,
+    ///     pipeline::ZastPipeline,
+    /// };
+    ///
+    /// let tokens = ZastLexer::new("x += 1").tokenize().unwrap();
+    /// let expr = ZastParser::new(tokens).parse_expression_entry().unwrap();
+    /// let Expr::Assignment { target, value } = expr.node else { panic!() };
+    /// assert!(matches!(&target.node, Expr::Identifier(name) if name == "x"));
+    /// let Expr::BinaryExpression { left, operator, right } = value.node else { panic!() };
+    /// assert!(matches!(&left.node, Expr::Identifier(name) if name == "x"));
+    /// assert_eq!(operator, TokenKind::Plus);
+    /// assert!(matches!(right.node, Expr::IntegerLiteral { value: 1, .. }));
+    ///
+    /// let src = "fn f(): i32 { const x = 1; x += 1; return x; }";
+    /// let errors = ZastPipeline::new().compile(src).errors;
+    /// assert_eq!(errors.errors()[0].get_error_code(), "E0019");
+    /// ```
+    ///
+    /// # Arguments
+    ///
+    /// * `target` - The already-parsed left-hand side of the assignment.
+    pub fn parse_assignment_expr(&mut self, target: Expression) -> Option<Expression> {
+        let op = self.current_token().kind;
+        let target_span = target.span;
+        self.advance(); // eat operator
+
+        let value = self.parse_right_operand(op)?;
+        let value_span = value.span;
+
+        let full_span = Span::merge(target_span, value_span);
+
+        let binary_op = match op {
+            TokenKind::PlusAssign => Some(TokenKind::Plus),
+            TokenKind::MinusAssign => Some(TokenKind::Minus),
+            TokenKind::MultiplyAssign => Some(TokenKind::Multiply),
+            TokenKind::DivideAssign => Some(TokenKind::Divide),
+            _ => None,
+        };
+
+        let value = match binary_op {
+            Some(operator) => Expr::BinaryExpression {
+                // a fresh node, so the read of the target has its own id
+                left: Box::new(target.node.clone().spanned(target_span)),
+                operator,
+                right: Box::new(value),
+            }
+            .spanned(full_span),
+            None => value,
+        };
+
+        Some(
+            Expr::Assignment {
+                target: Box::new(target),
+                value: Box::new(value),
+            }
+            .spanned(full_span),
+        )
+    }
+
+    /// Parses a ternary conditional expression, e.g. `c ? a : b`.
+    ///
+    /// Called as a LED function with the condition already parsed. The
+    /// then-branch is parsed at default precedence up to the `:`. The
+    /// else-branch is parsed via [`ZastParser::parse_right_operand`] for `?`,
+    /// so the operator is right-associative and
+    /// `a ? b : c ? d : e` nests as `a ? b : (c ? d : e)`.
+    ///
+    /// `:` has no NUD, LED, or precedence of its own, so it ends the
+    /// then-branch instead of being parsed as an operator. It is only consumed
+    /// here and where a type annotation or argument name is expected, after
+    /// a declared name, so a declaration can use both:
+    ///
+    /// ```rust
+    /// use zast::pipeline::ZastPipeline;
+    ///
+    /// let src = "fn f(cond: bool): i32 { let x: i32 = cond ? 1 : 2; x }";
+    /// assert!(ZastPipeline::new().compile(src).is_success());
+    /// ```
+    ///
+    /// Nested conditionals group to the right:
+    ///
+    /// ```rust
+    /// use zast::parser::ZastParser;
+    ///
+    /// let src = "fn f(a: bool, c: bool): i32 { a ? 1 : c ? 2 : 3 }";
+    /// let dump = ZastParser::from_source(src).unwrap().dump(src);
+    ///
+    /// assert!(dump.contains("Ternary `a ? 1 : c ? 2 : 3`"));
+    /// assert!(dump.contains("Ternary `c ? 2 : 3`"));
+    /// ```
+    ///
+    /// # Arguments
+    ///
+    /// * `condition` - The already-parsed condition expression.
+    pub fn parse_ternary_expr(&mut self, condition: Expression) -> Option<Expression> {
+        let condition_span = condition.span;
+        self.advance(); // eat '?'
+
+        let then_expr = self.try_parse_expr(Precedence::Default)?;
+
+        if !self.expect(vec![Expected::Token(TokenKind::Colon)]) {
+            return None;
+        }
+
+        let else_expr = self.parse_right_operand(TokenKind::Question)?;
+        let else_span = else_expr.span;
+
+        let full_span = Span::merge(condition_span, else_span);
+
+        Some(
+            Expr::Ternary {
+                condition: Box::new(condition),
+                then_expr: Box::new(then_expr),
+                else_expr: Box::new(else_expr),
+            }
+            .spanned(full_span),
+        )
+    }
+
+    /// Parses an index expression, e.g. `arr[0]`, `grid[i][j]`.
+    ///
+    /// Called as a LED function with the indexed expression already parsed.
+    /// Consumes the opening `[`, parses the index at default precedence, then
+    /// expects a closing `]`. The resulting span runs from the start of the
+    /// base expression to the closing bracket.
+    ///
+    /// ```rust
+    /// use zast::pipeline::ZastPipeline;
+    ///
+    /// let src = "fn f(arr: [i32; 4]): i32 { return arr[0]; }";
+    /// assert!(ZastPipeline::new().compile(src).is_success());
+    ///
+    /// let src = "fn f(n: i32): i32 { return n[0]; }";
+    /// let errors = ZastPipeline::new().compile(src).errors;
+    /// assert_eq!(errors.errors()[0].get_error_code(), "E0013");
+    /// ```
+    ///
+    /// # Arguments
+    ///
+    /// * `base` - The already-parsed expression being indexed.
+    pub fn parse_index_expr(&mut self, base: Expression) -> Option<Expression> {
+        let base_span = base.span;
+        self.advance(); // eat '['
+
+        let index = self.try_parse_expr(Precedence::Default)?;
+
+        let close_span = self.current_token().span;
+        if !self.expect(vec![Expected::Token(TokenKind::RightBracket)]) {
+            return None;
+        }
+
+        let full_span = Span::merge(base_span, close_span);
+
+        Some(
+            Expr::Index {
+                base: Box::new(base),
+                index: Box::new(index),
+            }
+            .spanned(full_span),
+        )
+    }
+
+    /// Parses a struct field access expression, e.g. `p.x`, `line.start.y`.
+    ///
+    /// Called as a LED function with the base expression already parsed.
+    /// Consumes the `.` token and expects an identifier naming the field.
+    /// When the base names an enum declared earlier, e.g. `Color.Red`, an
+    /// [`Expr::EnumVariant`] is produced instead.
+    ///
+    /// ```rust
+    /// use zast::pipeline::ZastPipeline;
+    ///
+    /// let point = "struct Point { x: i32, y: i32 }\n";
+    /// let src = format!("{}fn f(p: Point): i32 {{ return p.x; }}", point);
+    /// assert!(ZastPipeline::new().compile(&src).is_success());
+    ///
+    /// let src = format!("{}fn f(p: Point): i32 {{ return p.z; }}", point);
+    /// let errors = ZastPipeline::new().compile(&src).errors;
+    /// assert_eq!(errors.errors()[0].get_error_code(), "E0017");
+    /// assert!(errors.render_all(&src)[0].contains("No field 'z' on type 'Point'"));
+    /// ```
+    ///
+    /// # Arguments
+    ///
+    /// * `base` - The already-parsed expression whose field is accessed.
+    pub fn parse_field_access_expr(&mut self, base: Expression) -> Option<Expression> {
+        let base_span = base.span;
+        self.advance(); // eat '.'
+
+        if !self.check(vec![Expected::Token(TokenKind::Identifier)]) {
+            return None;
+        }
+
+        let field_span = self.current_token().span;
+        let field = self.current_token().literal.get_identifier()?;
+        self.advance();
+
+        let full_span = Span::merge(base_span, field_span);
+
+        if let Expr::Identifier(enum_name) = &base.node
+            && self.enum_names.contains(enum_name)
+        {
+            return Some(
+                Expr::EnumVariant {
+                    enum_name: enum_name.clone(),
+                    variant: field,
+                }
+                .spanned(full_span),
+            );
+        }
+
+        Some(
+            Expr::FieldAccess {
+                base: Box::new(base),
+                field,
+            }
+            .spanned(full_span),
+        )
+    }
+
+    /// Parses a call expression, e.g. `f()`, `add(1, 2)`, `add(a: 1, b: 2)`.
+    ///
+    /// Called as a LED function with the callee already parsed. Consumes the
+    /// opening `(`, then a comma-separated list of arguments via
+    /// [`ZastParser::parse_argument`], up to the closing `)`. A trailing comma
+    /// before the `)` is allowed.
+    ///
+    /// # Arguments
+    ///
+    /// * `callee` - The already-parsed expression being called.
+    pub fn parse_call_expr(&mut self, callee: Expression) -> Option<Expression> {
+        let callee_span = callee.span;
+        self.advance(); // eat '('
+
+        let mut arguments = Vec::new();
+        while self.current_token_kind() != TokenKind::RightParenthesis {
+            arguments.push(self.parse_argument()?);
+
+            if self.current_token_kind() == TokenKind::Comma {
+                self.advance();
+            } else {
+                break;
+            }
+        }
+
+        let close_span = self.current_token().span;
+        if !self.expect(vec![Expected::Token(TokenKind::RightParenthesis)]) {
+            return None;
+        }
+
+        let full_span = Span::merge(callee_span, close_span);
+
+        Some(
+            Expr::Call {
+                callee: Box::new(callee),
+                arguments,
+            }
+            .spanned(full_span),
+        )
+    }
+
+    /// Parses a single call argument, e.g. `1 + x` or `b: 2`.
+    ///
+    /// An identifier directly followed by `:` names the parameter the
+    /// argument fills; the value after it is parsed at default precedence.
+    /// Whether the name exists is checked during semantic analysis.
+    pub fn parse_argument(&mut self) -> Option<Argument> {
+        let start_span = self.current_token().span;
+
+        let name = if self.current_token_kind() == TokenKind::Identifier
+            && self.peek_token_kind() == TokenKind::Colon
+        {
+            let name = self.current_token().literal.get_identifier()?;
+            self.advance(); // eat name
+            self.advance(); // eat ':'
+            Some(name)
+        } else {
+            None
+        };
+
+        let value = self.try_parse_expr(Precedence::Default)?;
+        Some(Argument {
+            name,
+            span: Span::merge(start_span, value.span),
+            value,
+        })
+    }
+
+    /// Parses a cast expression, e.g. `x as u8` or `p as *i32`.
+    ///
+    /// Called as a LED function with the operand already parsed. `as` sits at
+    /// [`Precedence::Cast`], just below unary operators, so `-x as u8` casts
+    /// `-x` while `a * b as u8` casts only `b`. Which conversions are allowed
+    /// is checked during semantic analysis.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use zast::pipeline::ZastPipeline;
+    ///
+    /// assert!(ZastPipeline::new().compile("const a = 5 as u8;").is_success());
+    /// assert!(ZastPipeline::new().compile("const b = 3.0 as i32;").is_success());
+    /// assert!(!ZastPipeline::new().compile("const c = 3.0 as *i32;").is_success());
+    /// ```
+    ///
+    /// # Arguments
+    ///
+    /// * `expr` - The already-parsed expression being converted.
+    pub fn parse_cast_expr(&mut self, expr: Expression) -> Option<Expression> {
+        let expr_span = expr.span;
+        self.advance(); // eat 'as'
+
+        let target = self.try_parse_value_type()?;
+        let full_span = Span::merge(expr_span, self.previous_token().span);
+
+        Some(
+            Expr::Cast {
+                expr: Box::new(expr),
+                target,
+            }
+            .spanned(full_span),
+        )
+    }
+
     /// Parses a parenthesized grouping expression, e.g. `(a + b)`.
     ///
     /// Consumes the opening `(`, parses the inner expression at default
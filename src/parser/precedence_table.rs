@@ -1,8 +1,29 @@
-use num_enum::IntoPrimitive;
+use num_enum::{IntoPrimitive, TryFromPrimitive};
 
 use crate::lexer::tokens::TokenKind;
 
-#[derive(IntoPrimitive)]
+/// Binding power of infix operators, loosest first.
+///
+/// Bitwise operators bind tighter than comparisons and looser than
+/// arithmetic, with shifts above `&` above `^` above `|` as in C, so
+/// `a & b == c` is `(a & b) == c` and `a | b ^ c & d` is `a | (b ^ (c & d))`.
+///
+/// ```rust
+/// use zast::{ast::Expr, lexer::{ZastLexer, tokens::TokenKind}, parser::ZastParser};
+///
+/// let tokens = ZastLexer::new("a | b ^ c & d").tokenize().unwrap();
+/// let expr = ZastParser::new(tokens).parse_expression_entry().unwrap();
+///
+/// let Expr::BinaryExpression { operator, right, .. } = expr.node else { panic!() };
+/// assert_eq!(operator, TokenKind::Pipe);
+/// let Expr::BinaryExpression { operator, right, .. } = right.node else { panic!() };
+/// assert_eq!(operator, TokenKind::Caret);
+/// assert!(matches!(
+///     right.node,
+///     Expr::BinaryExpression { operator: TokenKind::Ampersand, .. }
+/// ));
+/// ```
+#[derive(IntoPrimitive, TryFromPrimitive)]
 #[repr(u8)]
 pub enum Precedence {
     Default,
@@ -12,8 +33,13 @@ pub enum Precedence {
     LogicalAnd,
     Equals,
     Comparison,
+    BitwiseOr,
+    BitwiseXor,
+    BitwiseAnd,
+    Shift,
     Additive,
     Multiplicative,
+    Cast,
     Unary,
     Exponent,
     Call,
@@ -23,10 +49,69 @@ pub enum Precedence {
 impl Precedence {
     pub fn get_precedence(token_kind: TokenKind) -> Option<Self> {
         match token_kind {
+            TokenKind::Assignment
+            | TokenKind::PlusAssign
+            | TokenKind::MinusAssign
+            | TokenKind::MultiplyAssign
+            | TokenKind::DivideAssign => Some(Self::Assignment),
+            TokenKind::Question => Some(Self::Ternary),
+            TokenKind::LogicalOr => Some(Self::LogicalOr),
+            TokenKind::LogicalAnd => Some(Self::LogicalAnd),
+            TokenKind::Equal | TokenKind::NotEqual => Some(Self::Equals),
+            TokenKind::Less
+            | TokenKind::LessEqual
+            | TokenKind::Greater
+            | TokenKind::GreaterEqual => Some(Self::Comparison),
+            TokenKind::Pipe => Some(Self::BitwiseOr),
+            TokenKind::Caret => Some(Self::BitwiseXor),
+            TokenKind::Ampersand => Some(Self::BitwiseAnd),
+            TokenKind::Shl | TokenKind::Shr => Some(Self::Shift),
             TokenKind::Plus | TokenKind::Minus => Some(Self::Additive),
             TokenKind::Multiply | TokenKind::Divide => Some(Self::Multiplicative),
+            TokenKind::As => Some(Self::Cast),
+            TokenKind::DoubleStar => Some(Self::Exponent),
+            TokenKind::LeftBracket | TokenKind::Dot => Some(Self::Call),
             TokenKind::LeftParenthesis => Some(Self::Grouping),
             _ => None,
         }
     }
+
+    /// Returns how a chain of the infix operator `token_kind` groups:
+    /// assignment, `?:`, and `**` are right-associative, so `a = b = c` is
+    /// `a = (b = c)`; every other operator is left-associative, so
+    /// `a - b - c` is `(a - b) - c`.
+    pub fn associativity(token_kind: TokenKind) -> Associativity {
+        match token_kind {
+            TokenKind::Assignment
+            | TokenKind::PlusAssign
+            | TokenKind::MinusAssign
+            | TokenKind::MultiplyAssign
+            | TokenKind::DivideAssign
+            | TokenKind::Question
+            | TokenKind::DoubleStar => Associativity::Right,
+            _ => Associativity::Left,
+        }
+    }
+
+    /// Returns the precedence the right operand of `token_kind` is parsed at:
+    /// its own level if left-associative, so an equal operator ends the
+    /// operand, or one level below if right-associative, so the operand
+    /// takes the rest of the chain.
+    pub fn right_operand(token_kind: TokenKind) -> Self {
+        let prec = Self::get_precedence(token_kind).unwrap_or(Self::Default);
+
+        match Self::associativity(token_kind) {
+            Associativity::Left => prec,
+            Associativity::Right => {
+                Self::try_from(u8::from(prec).saturating_sub(1)).unwrap_or(Self::Default)
+            }
+        }
+    }
+}
+
+/// How a chain of operators with the same [`Precedence`] groups.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Associativity {
+    Left,
+    Right,
 }
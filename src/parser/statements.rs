@@ -1,6 +1,6 @@
 use crate::{
-    ast::{FunctionParameter, Statement, Stmt},
-    error_handler::zast_errors::Expected,
+    ast::{Expression, FunctionParameter, Statement, Stmt},
+    error_handler::zast_errors::{Expected, ZastError},
     lexer::tokens::{Span, TokenKind},
     parser::{ZastParser, precedence_table::Precedence},
 };
@@ -13,6 +13,20 @@ impl ZastParser {
     /// parser falls back to parsing an expression statement, which must be
     /// terminated by a `;`.
     ///
+    /// Statements ending in a block (`fn`, `if`, `while` and bare blocks) are
+    /// always dispatched through the lookup table, so they never reach the
+    /// expression-statement path and need no `;` after their closing `}`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use zast::parser::ZastParser;
+    ///
+    /// let src = "fn f(c: bool): void { if c {} if c {} else {} while c {} }";
+    /// let program = ZastParser::from_source(src).unwrap();
+    /// assert_eq!(program.body.len(), 1);
+    /// ```
+    ///
     /// # Returns
     ///
     /// `Some(Statement)` on success, or `None` if parsing fails.
@@ -22,16 +36,19 @@ impl ZastParser {
         }
 
         let stmt_expr = self.try_parse_expr(Precedence::Default)?;
-        let stmt_expr_span = stmt_expr.span;
-        let stmt = Stmt::Expression {
-            expression: stmt_expr,
-        };
+        self.finish_expression_stmt(stmt_expr)
+    }
+
+    /// Wraps an already-parsed expression into a [`Stmt::Expression`],
+    /// consuming its terminating `;`.
+    fn finish_expression_stmt(&mut self, expression: Expression) -> Option<Statement> {
+        let span = expression.span;
 
         if !self.expect(vec![Expected::Token(TokenKind::Semicolon)]) {
             return None;
         }
 
-        Some(stmt.spanned(stmt_expr_span))
+        Some(Stmt::Expression { expression }.spanned(span))
     }
 
     /// Parses a function declaration, e.g. `fn foo(a: i32): void { ... }`.
@@ -59,12 +76,7 @@ impl ZastParser {
         let body = self.parse_block_statement()?;
         let body_span = body.span;
 
-        let full_span = Span {
-            ln_start: fn_tok_span.ln_start,
-            ln_end: body_span.ln_end,
-            col_start: fn_tok_span.col_start,
-            col_end: body_span.col_end,
-        };
+        let full_span = Span::merge(fn_tok_span, body_span);
 
         Some(
             Stmt::FunctionDeclaration {
@@ -82,6 +94,29 @@ impl ZastParser {
     /// Expects an opening `(`. Returns an empty vec for `()`. Supports
     /// optional trailing commas. Each parameter is a name-type pair separated
     /// by `:`.
+    ///
+    /// A malformed parameter does not abort the list: its error is recorded,
+    /// [`ZastParser::sync_param`] skips to the next `,` or `)`, and the
+    /// remaining parameters are still collected, so `fn f(a:, b: i32)` reports
+    /// `a` and keeps `b`. Only a missing `(` or `)` fails the whole list.
+    ///
+    /// Probing for an empty list or a trailing comma uses
+    /// [`ZastParser::matches`], so neither reports an error on its own.
+    ///
+    /// ```rust
+    /// use zast::parser::ZastParser;
+    ///
+    /// assert!(ZastParser::from_source("fn f(): void {}").is_ok());
+    /// assert!(ZastParser::from_source("fn g(a: i32,): void {}").is_ok());
+    ///
+    /// // `a` is missing its type, yet `b` is still parsed and checked
+    /// let columns = |src: &str| {
+    ///     let errors = ZastParser::from_source(src).unwrap_err();
+    ///     errors.errors().iter().map(|e| e.get_span().col_start).collect::<Vec<_>>()
+    /// };
+    /// assert_eq!(columns("fn f(a:, b: i32): void {}"), [8]);
+    /// assert_eq!(columns("fn f(a:, b i32): void {}"), [8, 12]);
+    /// ```
     fn parse_function_parameter(&mut self) -> Option<Vec<FunctionParameter>> {
         if !self.expect(vec![Expected::Token(TokenKind::LeftParenthesis)]) {
             return None;
@@ -89,23 +124,18 @@ impl ZastParser {
 
         let mut params = Vec::new();
 
-        // empty parameter list
-        if self.current_token_kind() == TokenKind::RightParenthesis {
-            self.advance();
-            return Some(params);
-        }
-
-        params.push(self.parse_single_param()?);
-
-        while !self.is_at_eof() && self.current_token_kind() == TokenKind::Comma {
-            self.advance(); // eat ','
+        while !self.is_at_eof() && !self.matches(&[Expected::Token(TokenKind::RightParenthesis)]) {
+            match self.parse_single_param() {
+                Some(param) => params.push(param),
+                None => self.sync_param(),
+            }
 
-            // optional trailing comma
-            if self.current_token_kind() == TokenKind::RightParenthesis {
+            // a trailing comma simply ends the loop at ')'
+            if self.matches(&[Expected::Token(TokenKind::Comma)]) {
+                self.advance(); // eat ','
+            } else {
                 break;
             }
-
-            params.push(self.parse_single_param()?);
         }
 
         if !self.expect(vec![Expected::Token(TokenKind::RightParenthesis)]) {
@@ -115,9 +145,52 @@ impl ZastParser {
         Some(params)
     }
 
+    /// Skips the rest of a malformed parameter, stopping without consuming at
+    /// the next `,` or `)` outside any nested delimiters. A `{` or `;` at the
+    /// same level also stops the scan, so a missing `)` never swallows the
+    /// function body.
+    fn sync_param(&mut self) {
+        let mut depth = 0usize;
+
+        while !self.is_at_eof() {
+            match self.current_token_kind() {
+                TokenKind::Comma | TokenKind::RightParenthesis if depth == 0 => return,
+                TokenKind::LeftBrace | TokenKind::Semicolon if depth == 0 => return,
+                TokenKind::LeftParenthesis | TokenKind::LeftBracket => depth += 1,
+                // a `]` may close a bracket opened before the error, e.g. in `[i32; g()]`
+                TokenKind::RightParenthesis | TokenKind::RightBracket => {
+                    depth = depth.saturating_sub(1)
+                }
+                _ => {}
+            }
+            self.advance();
+        }
+    }
+
     /// Parses a single function parameter, e.g. `a: i32` or `ptr: *u8`.
     ///
-    /// Expects the form: `<identifier> : <type>`
+    /// Expects the form: `<identifier> : <type>`. The parameter's span runs
+    /// from the name through the last token of the type, so errors about a
+    /// parameter, such as a redeclared name, point at exactly that parameter.
+    ///
+    /// ```rust
+    /// use zast::pipeline::ZastPipeline;
+    ///
+    /// let errors = ZastPipeline::new().compile("fn f(a: i32, a: *u8): void {}").errors;
+    /// let duplicate = &errors.errors()[0];
+    /// let span = duplicate.get_span();
+    ///
+    /// // `a: *u8`
+    /// assert_eq!((span.col_start, span.col_end), (14, 19));
+    /// assert!(duplicate.get_error_msg().starts_with("Parameter 'a' already declared"));
+    ///
+    /// let errors = ZastPipeline::new().compile("fn f(a: i32, a: i32): void {}").errors;
+    /// assert_eq!(errors.errors()[0].get_error_code(), "E0038");
+    /// assert_eq!(
+    ///     errors.errors()[0].get_error_msg(),
+    ///     "Parameter 'a' already declared at '1:6-11'"
+    /// );
+    /// ```
     ///
     /// Used by [`ZastParser::parse_function_parameter`] to avoid duplicating
     /// parameter parsing logic between the first parameter and subsequent ones.
@@ -135,14 +208,10 @@ impl ZastParser {
         }
 
         let annotated_type = self.try_parse_value_type()?;
-        let type_span = self.current_token().span;
+        // the type's last token, not the `,` or `)` after it
+        let type_span = self.previous_token().span;
 
-        let span = Span {
-            ln_start: name_span.ln_start,
-            ln_end: type_span.ln_end,
-            col_start: name_span.col_start,
-            col_end: type_span.col_end,
-        };
+        let span = Span::merge(name_span, type_span);
 
         Some(FunctionParameter {
             name,
@@ -151,12 +220,34 @@ impl ZastParser {
         })
     }
 
-    /// Parses a block statement, e.g. `{ let x: i32 = 5; }`.
+    /// Parses a block statement, e.g. `{ let x: i32 = 5; }` or `{ x = 1; x }`.
     ///
     /// Consumes the opening `{`, parses zero or more statements until `}` is
     /// reached, then consumes the closing `}`. The full span covers from `{`
     /// to `}` inclusive.
-    fn parse_block_statement(&mut self) -> Option<Statement> {
+    ///
+    /// An expression immediately followed by `}` needs no `;` and is recorded
+    /// as the block's tail expression, so `{ x; }` holds an expression
+    /// statement while `{ x }` holds a tail.
+    ///
+    /// ```rust
+    /// use zast::{ast::Stmt, parser::ZastParser};
+    ///
+    /// let block = |src: &str| {
+    ///     let program = ZastParser::from_source(src).unwrap();
+    ///     let Stmt::FunctionDeclaration { body, .. } = &program.body[0].node else {
+    ///         unreachable!()
+    ///     };
+    ///     let Stmt::BlockStatement { statements, tail } = &body.node else {
+    ///         unreachable!()
+    ///     };
+    ///     (statements.len(), tail.is_some())
+    /// };
+    ///
+    /// assert_eq!(block("fn f(x: i32): void { x; }"), (1, false));
+    /// assert_eq!(block("fn f(x: i32): i32 { x }"), (0, true));
+    /// ```
+    pub fn parse_block_statement(&mut self) -> Option<Statement> {
         let lb_span = self.current_token().span;
 
         if !self.expect(vec![Expected::Token(TokenKind::LeftBrace)]) {
@@ -165,9 +256,21 @@ impl ZastParser {
 
         let mut stmts = Vec::new();
 
+        let mut tail = None;
+
         while !self.is_at_eof() && self.current_token_kind() != TokenKind::RightBrace {
-            let stmt = self.try_parse_stmt()?;
-            stmts.push(Box::new(stmt));
+            if self.stmt_lookup.contains_key(&self.current_token_kind()) {
+                stmts.push(Box::new(self.try_parse_stmt()?));
+                continue;
+            }
+
+            let expr = self.try_parse_expr(Precedence::Default)?;
+            if self.current_token_kind() == TokenKind::RightBrace {
+                tail = Some(Box::new(expr));
+                break;
+            }
+
+            stmts.push(Box::new(self.finish_expression_stmt(expr)?));
         }
 
         let rb_span = self.current_token().span;
@@ -176,22 +279,40 @@ impl ZastParser {
             return None;
         }
 
-        let full_span = Span {
-            ln_start: lb_span.ln_start,
-            ln_end: rb_span.ln_end,
-            col_start: lb_span.col_start,
-            col_end: rb_span.col_end,
-        };
+        let full_span = Span::merge(lb_span, rb_span);
 
-        Some(Stmt::BlockStatement { statements: stmts }.spanned(full_span))
+        Some(
+            Stmt::BlockStatement {
+                statements: stmts,
+                tail,
+            }
+            .spanned(full_span),
+        )
     }
 
-    /// Parses a variable declaration, e.g. `const x: i32 = 5;` or `let y: *u8 = &x;`.
+    /// Parses a variable declaration, e.g. `const x: i32 = 5;` or `let y = &x;`.
     ///
     /// The mutability of the variable is determined by the declaring keyword:
     /// `let` produces a mutable binding, `const` produces an immutable one.
+    /// The type annotation is optional; without one, the binding's type is
+    /// inferred from the initializer during semantic analysis.
+    ///
+    /// Expects the form: `<keyword> <identifier> [: <type>] = <expr> ;`
+    ///
+    /// ```rust
+    /// use zast::pipeline::ZastPipeline;
+    ///
+    /// let src = "fn f(): *i32 { let x = 5; const p = &x; return p; }";
+    /// assert!(ZastPipeline::new().compile(src).is_success());
+    ///
+    /// let src = "fn f(): i64 { let x = 5; return x; }";
+    /// let errors = ZastPipeline::new().compile(src).errors;
+    /// assert!(errors.render_all(src)[0].contains("expected 'i64', found 'i32'"));
     ///
-    /// Expects the form: `<keyword> <identifier> : <type> = <expr> ;`
+    /// let src = "fn f(): bool { let x = 5; const p = &x; return p; }";
+    /// let errors = ZastPipeline::new().compile(src).errors;
+    /// assert!(errors.render_all(src)[0].contains("expected 'bool', found '*i32'"));
+    /// ```
     pub fn parse_variable_declaration(&mut self) -> Option<Statement> {
         let decl_tok_kind = self.current_token().kind;
         let decl_span = self.current_token().span;
@@ -204,13 +325,19 @@ impl ZastParser {
         let identifier = self.current_token().literal.get_identifier()?;
         self.advance();
 
-        if !self.expect(vec![Expected::Token(TokenKind::Colon)]) {
-            return None;
-        }
+        let value_type = if self.current_token_kind() == TokenKind::Colon {
+            self.advance(); // eat ':'
+            Some(self.try_parse_value_type()?)
+        } else {
+            None
+        };
 
-        let value_type = self.try_parse_value_type()?;
+        let mut expected = vec![Expected::Token(TokenKind::Assignment)];
+        if value_type.is_none() {
+            expected.insert(0, Expected::Token(TokenKind::Colon));
+        }
 
-        if !self.expect(vec![Expected::Token(TokenKind::Assignment)]) {
+        if !self.expect(expected) {
             return None;
         }
 
@@ -221,12 +348,7 @@ impl ZastParser {
             return None;
         }
 
-        let full_span = Span {
-            ln_start: decl_span.ln_start,
-            ln_end: value_span.ln_end,
-            col_start: decl_span.col_start,
-            col_end: value_span.col_end,
-        };
+        let full_span = Span::merge(decl_span, value_span);
 
         Some(
             Stmt::VariableDeclaration {
@@ -238,4 +360,295 @@ impl ZastParser {
             .spanned(full_span),
         )
     }
+
+    /// Parses an import statement, e.g. `import "math.zast";`.
+    ///
+    /// The path is kept verbatim; resolving it to source text is left to the
+    /// semantic analyzer's import resolver.
+    ///
+    /// Expects the form: `import <string> ;`
+    ///
+    /// ```rust
+    /// use zast::{ast::Stmt, parser::ZastParser};
+    ///
+    /// let program = ZastParser::from_source("import \"lib/math.zast\";").unwrap();
+    /// assert!(matches!(&program.body[0].node, Stmt::Import { path } if path == "lib/math.zast"));
+    /// ```
+    pub fn parse_import_statement(&mut self) -> Option<Statement> {
+        let import_span = self.current_token().span;
+        self.advance(); // eat 'import'
+
+        if !self.check(vec![Expected::Token(TokenKind::String)]) {
+            return None;
+        }
+
+        let path_span = self.current_token().span;
+        let path = self.current_token().literal.get_string()?;
+        self.advance();
+
+        if !self.expect(vec![Expected::Token(TokenKind::Semicolon)]) {
+            return None;
+        }
+
+        let full_span = Span::merge(import_span, path_span);
+
+        Some(Stmt::Import { path }.spanned(full_span))
+    }
+
+    /// Parses a struct declaration, e.g. `struct Point { x: i32, y: i32 }`.
+    ///
+    /// Each field shares the `<identifier> : <type>` shape of a function
+    /// parameter and is parsed via [`ZastParser::parse_single_param`]. Fields
+    /// are separated by commas, with an optional trailing comma. A field
+    /// named twice is reported during semantic analysis, like a duplicate
+    /// parameter.
+    ///
+    /// Expects the form: `struct <identifier> { [<field> {, <field>} [,]] }`
+    ///
+    /// ```rust
+    /// use zast::{ast::Stmt, parser::ZastParser, pipeline::ZastPipeline};
+    ///
+    /// let src = "struct Point { x: i32, y: i32 }";
+    /// let program = ZastParser::from_source(src).unwrap();
+    /// let Stmt::StructDeclaration { name, fields } = &program.body[0].node else {
+    ///     unreachable!()
+    /// };
+    /// assert_eq!(name, "Point");
+    /// assert_eq!(fields.iter().map(|field| field.name.as_str()).collect::<Vec<_>>(), ["x", "y"]);
+    ///
+    /// let src = "struct Point { x: i32, y: i32 }\nfn f(p: Point): Point { return p; }";
+    /// assert!(ZastPipeline::new().compile(src).is_success());
+    ///
+    /// let errors = ZastPipeline::new().compile("struct S { a: i32, a: i32 }").errors;
+    /// assert_eq!(errors.errors()[0].get_error_code(), "E0047");
+    /// assert_eq!(errors.errors()[0].get_error_msg(), "Field 'a' already declared at '1:12-17'");
+    /// ```
+    pub fn parse_struct_declaration(&mut self) -> Option<Statement> {
+        let struct_span = self.current_token().span;
+        self.advance(); // eat 'struct'
+
+        if !self.check(vec![Expected::Token(TokenKind::Identifier)]) {
+            return None;
+        }
+
+        let name = self.current_token().literal.get_identifier()?;
+        self.advance();
+
+        if !self.expect(vec![Expected::Token(TokenKind::LeftBrace)]) {
+            return None;
+        }
+
+        let mut fields = Vec::new();
+
+        while !self.is_at_eof() && self.current_token_kind() != TokenKind::RightBrace {
+            fields.push(self.parse_single_param()?);
+
+            if self.current_token_kind() != TokenKind::Comma {
+                break;
+            }
+            self.advance(); // eat ','
+        }
+
+        let rb_span = self.current_token().span;
+
+        if !self.expect(vec![Expected::Token(TokenKind::RightBrace)]) {
+            return None;
+        }
+
+        let full_span = Span::merge(struct_span, rb_span);
+
+        Some(Stmt::StructDeclaration { name, fields }.spanned(full_span))
+    }
+
+    /// Parses an enum declaration, e.g. `enum Color { Red, Green }`.
+    ///
+    /// Variants are plain identifiers separated by commas, with an optional
+    /// trailing comma. The enum's name is remembered so later uses such as
+    /// `Color.Red` parse as [`Expr::EnumVariant`](crate::ast::Expr::EnumVariant).
+    ///
+    /// A variant named twice is reported as a [`ZastError::DuplicateVariant`]
+    /// citing the first one.
+    ///
+    /// Expects the form: `enum <identifier> { [<identifier> {, <identifier>} [,]] }`
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use zast::{ast::Stmt, parser::ZastParser, pipeline::ZastPipeline};
+    ///
+    /// let program = ZastParser::from_source("enum Color { Red, Green }").unwrap();
+    /// let Stmt::EnumDeclaration { name, variants } = &program.body[0].node else {
+    ///     unreachable!()
+    /// };
+    /// assert_eq!((name.as_str(), variants.len()), ("Color", 2));
+    ///
+    /// let red = "enum Color { Red, Green }\nfn f(): Color { Color.Red }";
+    /// assert!(ZastPipeline::new().compile(red).is_success());
+    ///
+    /// let blue = "enum Color { Red, Green }\nfn f(): Color { Color.Blue }";
+    /// let errors = ZastPipeline::new().compile(blue).errors.render_all(blue);
+    /// assert!(errors[0].contains("No variant 'Blue' in enum 'Color'"));
+    ///
+    /// let errors = ZastParser::from_source("enum E { A, B, A }").unwrap_err();
+    /// assert_eq!(errors.errors()[0].get_error_code(), "E0046");
+    /// assert_eq!(errors.errors()[0].get_error_msg(), "Variant 'A' already declared at '1:10'");
+    /// assert_eq!(errors.errors()[0].get_span().col_start, 16);
+    /// ```
+    pub fn parse_enum_declaration(&mut self) -> Option<Statement> {
+        let enum_span = self.current_token().span;
+        self.advance(); // eat 'enum'
+
+        if !self.check(vec![Expected::Token(TokenKind::Identifier)]) {
+            return None;
+        }
+
+        let name = self.current_token().literal.get_identifier()?;
+        self.advance();
+
+        if !self.expect(vec![Expected::Token(TokenKind::LeftBrace)]) {
+            return None;
+        }
+
+        let mut variants = Vec::new();
+        let mut variant_spans = Vec::new();
+
+        while !self.is_at_eof() && self.current_token_kind() != TokenKind::RightBrace {
+            if !self.check(vec![Expected::Token(TokenKind::Identifier)]) {
+                return None;
+            }
+            let variant = self.current_token().literal.get_identifier()?;
+            let variant_span = self.current_token().span;
+
+            // a duplicate would share the original's index once lowered
+            match variants.iter().position(|v| *v == variant) {
+                Some(original) => self.throw_error(ZastError::DuplicateVariant {
+                    span: variant_span,
+                    name: variant,
+                    original_span: variant_spans[original],
+                }),
+                None => {
+                    variants.push(variant);
+                    variant_spans.push(variant_span);
+                }
+            }
+            self.advance();
+
+            if self.current_token_kind() != TokenKind::Comma {
+                break;
+            }
+            self.advance(); // eat ','
+        }
+
+        let rb_span = self.current_token().span;
+
+        if !self.expect(vec![Expected::Token(TokenKind::RightBrace)]) {
+            return None;
+        }
+
+        let full_span = Span::merge(enum_span, rb_span);
+        self.enum_names.insert(name.clone());
+
+        Some(Stmt::EnumDeclaration { name, variants }.spanned(full_span))
+    }
+
+    /// Parses a return statement, e.g. `return;` or `return a + b;`.
+    ///
+    /// The value is optional; a bare `return;` exits a `void` function.
+    /// Whether the statement appears inside a function, and whether its value
+    /// matches the return type, is checked during semantic analysis.
+    ///
+    /// Expects the form: `return [<expr>] ;`
+    pub fn parse_return_statement(&mut self) -> Option<Statement> {
+        let return_span = self.current_token().span;
+        self.advance(); // eat 'return'
+
+        let value = if self.current_token_kind() == TokenKind::Semicolon {
+            None
+        } else {
+            Some(self.try_parse_expr(Precedence::Default)?)
+        };
+
+        let end_span = value.as_ref().map_or(return_span, |v| v.span);
+
+        if !self.expect(vec![Expected::Token(TokenKind::Semicolon)]) {
+            return None;
+        }
+
+        let full_span = Span::merge(return_span, end_span);
+
+        Some(Stmt::Return { value }.spanned(full_span))
+    }
+
+    /// Parses an if statement, e.g. `if a < b { ... } else { ... }`.
+    ///
+    /// The condition is parsed at default precedence and needs no
+    /// parentheses; both branches must be blocks. An `else` may be followed
+    /// by another `if` instead of a block, so `else if` chains nest as an
+    /// [`Stmt::If`] in the else branch. The span runs from `if` to the end of
+    /// the last branch.
+    ///
+    /// Expects the form: `if <expr> <block> [else (<block> | <if>)]`
+    pub fn parse_if_statement(&mut self) -> Option<Statement> {
+        let if_span = self.current_token().span;
+        self.advance(); // eat 'if'
+
+        let condition = self.try_parse_expr(Precedence::Default)?;
+
+        if !self.check(vec![Expected::Token(TokenKind::LeftBrace)]) {
+            return None;
+        }
+        let then_branch = self.parse_block_statement()?;
+
+        let else_branch = if self.current_token_kind() == TokenKind::Else {
+            self.advance(); // eat 'else'
+
+            if self.current_token_kind() == TokenKind::If {
+                Some(self.parse_if_statement()?)
+            } else {
+                Some(self.parse_block_statement()?)
+            }
+        } else {
+            None
+        };
+
+        let end_span = else_branch.as_ref().map_or(then_branch.span, |e| e.span);
+        let full_span = Span::merge(if_span, end_span);
+
+        Some(
+            Stmt::If {
+                condition,
+                then_branch: Box::new(then_branch),
+                else_branch: else_branch.map(Box::new),
+            }
+            .spanned(full_span),
+        )
+    }
+
+    /// Parses a while loop, e.g. `while i < n { i += 1; }`.
+    ///
+    /// As with [`ZastParser::parse_if_statement`], the condition needs no
+    /// parentheses and the body must be a block.
+    ///
+    /// Expects the form: `while <expr> <block>`
+    pub fn parse_while_statement(&mut self) -> Option<Statement> {
+        let while_span = self.current_token().span;
+        self.advance(); // eat 'while'
+
+        let condition = self.try_parse_expr(Precedence::Default)?;
+
+        if !self.check(vec![Expected::Token(TokenKind::LeftBrace)]) {
+            return None;
+        }
+        let body = self.parse_block_statement()?;
+
+        let full_span = Span::merge(while_span, body.span);
+
+        Some(
+            Stmt::While {
+                condition,
+                body: Box::new(body),
+            }
+            .spanned(full_span),
+        )
+    }
 }
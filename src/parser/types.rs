@@ -1,8 +1,8 @@
 use crate::{
     error_handler::zast_errors::{Expected, ZastError},
     lexer::tokens::TokenKind,
-    parser::ZastParser,
-    types::{annotated_type::AnnotatedType, return_type::ReturnType},
+    parser::{ZastParser, precedence_table::Precedence},
+    types::{annotated_type::AnnotatedType, const_expr::ConstExpr, return_type::ReturnType},
 };
 
 impl ZastParser {
@@ -29,12 +29,16 @@ impl ZastParser {
     /// Parses a value type annotation, e.g. `i32`, `*u8`, `**bool`.
     ///
     /// Dispatches based on the current token:
-    /// - `*` → pointer type via [`ZastParser::parse_pointer_type`]
+    /// - `*` or `**` → pointer type via [`ZastParser::parse_pointer_type`]
+    /// - `[` → array type via [`ZastParser::parse_array_type`]
+    /// - `fn` → function type via [`ZastParser::parse_function_type`]
     /// - `Identifier` → primitive type via [`ZastParser::parse_primitive_type`]
     /// - anything else → emits an error and returns `None`
     pub(crate) fn try_parse_value_type(&mut self) -> Option<AnnotatedType> {
         match self.current_token_kind() {
-            TokenKind::Multiply => self.parse_pointer_type(),
+            TokenKind::Multiply | TokenKind::DoubleStar => self.parse_pointer_type(),
+            TokenKind::LeftBracket => self.parse_array_type(),
+            TokenKind::Fn => self.parse_function_type(),
             TokenKind::Identifier => self.parse_primitive_type(),
             _ => {
                 let cur_tok = self.current_token();
@@ -52,12 +56,128 @@ impl ZastParser {
     ///
     /// Consumes the `*` token and recursively parses the pointee type via
     /// [`ZastParser::try_parse_value_type`], allowing arbitrary pointer depth.
+    /// A `**` token, lexed as one, adds two levels.
     pub(crate) fn parse_pointer_type(&mut self) -> Option<AnnotatedType> {
-        self.advance(); // eat '*'
-        let inner = self.try_parse_value_type()?;
+        let double = self.current_token_kind() == TokenKind::DoubleStar;
+        self.advance(); // eat '*' or '**'
+
+        let mut inner = self.try_parse_value_type()?;
+        if double {
+            inner = AnnotatedType::Pointer(Box::new(inner));
+        }
         Some(AnnotatedType::Pointer(Box::new(inner)))
     }
 
+    /// Parses an array type annotation, e.g. `[i32; 4]`, `[*u8; 16]`, `[i32; N * 2]`.
+    ///
+    /// Expects the form: `[ <type> ; <length> ]`. The element type is parsed
+    /// via [`ZastParser::try_parse_value_type`], so arrays of pointers, pointers
+    /// to arrays, and nested arrays are all supported.
+    ///
+    /// The length is any expression that converts to a [`ConstExpr`]: integer
+    /// literals, names of `const` bindings, negation, `+`, `-`, and `*`. It is
+    /// evaluated during semantic analysis. Any other expression is reported as
+    /// a [`ZastError::NonConstantArrayLength`].
+    ///
+    /// ```rust
+    /// use zast::{ast::Stmt, parser::ZastParser, types::ValueType};
+    ///
+    /// let program = ZastParser::from_source("fn f(a: [i32; 4], b: *[u8; 16]): void {}").unwrap();
+    /// let Stmt::FunctionDeclaration { parameters, .. } = &program.body[0].node else {
+    ///     unreachable!()
+    /// };
+    ///
+    /// for (param, expected) in parameters.iter().zip(["[i32; 4]", "*[u8; 16]"]) {
+    ///     assert_eq!(param.annotated_type.to_string(), expected);
+    ///     let resolved = ValueType::try_from_annotated_type(&param.annotated_type).unwrap();
+    ///     assert_eq!(resolved.to_string(), expected);
+    /// }
+    /// ```
+    pub(crate) fn parse_array_type(&mut self) -> Option<AnnotatedType> {
+        self.advance(); // eat '['
+        let element = self.try_parse_value_type()?;
+
+        if !self.expect(vec![Expected::Token(TokenKind::Semicolon)]) {
+            return None;
+        }
+
+        let len_expr = self.try_parse_expr(Precedence::Default)?;
+        let Some(len) = ConstExpr::from_expr(&len_expr) else {
+            self.throw_error(ZastError::NonConstantArrayLength {
+                span: len_expr.span,
+            });
+            return None;
+        };
+
+        if !self.expect(vec![Expected::Token(TokenKind::RightBracket)]) {
+            return None;
+        }
+
+        Some(AnnotatedType::Array(Box::new(element), len))
+    }
+
+    /// Parses a function type annotation, e.g. `fn(i32, i32): i32`,
+    /// `fn(): void`.
+    ///
+    /// Expects the form: `fn ( <type>, ... ) : <return type>`. Parameter types
+    /// are parsed via [`ZastParser::try_parse_value_type`] and the return type
+    /// via [`ZastParser::try_parse_return_type`], so function types nest, e.g.
+    /// `fn(fn(i32): i32): void`. As in a function declaration, the return type
+    /// is mandatory and a trailing comma is not allowed.
+    ///
+    /// ```rust
+    /// use zast::pipeline::ZastPipeline;
+    ///
+    /// let program = |callback: &str| {
+    ///     format!(
+    ///         "fn apply(cb: fn(i32): i32, x: i32): i32 {{ return cb(x); }}
+    ///          fn double(n: i32): i32 {{ return n * 2; }}
+    ///          fn widen(n: i64): i64 {{ return n; }}
+    ///          fn main(): i32 {{ return apply({}, 2); }}",
+    ///         callback
+    ///     )
+    /// };
+    ///
+    /// assert!(ZastPipeline::new().compile(&program("double")).is_success());
+    ///
+    /// let src = program("widen");
+    /// let rendered = ZastPipeline::new().compile(&src).errors.render_all(&src);
+    /// assert!(rendered[0].contains("expected 'fn(i32): i32', found 'fn(i64): i64'"));
+    /// ```
+    pub(crate) fn parse_function_type(&mut self) -> Option<AnnotatedType> {
+        self.advance(); // eat 'fn'
+
+        if !self.expect(vec![Expected::Token(TokenKind::LeftParenthesis)]) {
+            return None;
+        }
+
+        let mut params = Vec::new();
+        if self.current_token_kind() != TokenKind::RightParenthesis {
+            loop {
+                params.push(self.try_parse_value_type()?);
+
+                if self.current_token_kind() != TokenKind::Comma {
+                    break;
+                }
+                self.advance(); // eat ','
+            }
+        }
+
+        if !self.expect(vec![Expected::Token(TokenKind::RightParenthesis)]) {
+            return None;
+        }
+
+        if !self.expect(vec![Expected::Token(TokenKind::Colon)]) {
+            return None;
+        }
+
+        let return_type = self.try_parse_return_type()?;
+        Some(AnnotatedType::Function {
+            params,
+            return_type: Box::new(return_type),
+        })
+    }
+
     /// Parses a primitive type annotation, e.g. `i32`, `u8`, `bool`.
     ///
     /// Consumes the identifier token and stores its name as a [`AnnotatedType::Primitive`].
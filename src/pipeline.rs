@@ -0,0 +1,156 @@
+use crate::{
+    ast::ZastProgram,
+    config::ZastConfig,
+    error_handler::ZastErrorCollector,
+    lexer::ZastLexer,
+    parser::ZastParser,
+    sema::{ImportResolver, ZastSemanticAnalyzer},
+};
+
+/// A stage of the Zast front end, in the order the pipeline runs them.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ZastPhase {
+    Lexing,
+    Parsing,
+    Analysis,
+}
+
+/// The outcome of running the pipeline over a single source text.
+#[derive(Debug)]
+pub struct ZastCompilation {
+    /// The parsed program. Present whenever parsing succeeded, even if
+    /// semantic analysis then reported errors.
+    pub program: Option<ZastProgram>,
+
+    /// The diagnostics reported by the furthest phase reached. On success this
+    /// holds only warnings.
+    pub errors: ZastErrorCollector,
+
+    /// The furthest phase the pipeline reached.
+    pub phase: ZastPhase,
+}
+
+impl ZastCompilation {
+    /// Returns `true` if the source parsed into an AST.
+    pub fn has_ast(&self) -> bool {
+        self.program.is_some()
+    }
+
+    /// Returns `true` if every phase completed without errors. Warnings do not
+    /// affect success unless [`ZastConfig::deny_warnings`] is set.
+    ///
+    /// ```rust
+    /// use zast::{config::ZastConfig, pipeline::ZastPipeline};
+    ///
+    /// let src = "fn main(): void { const unused = 1; }";
+    /// assert!(ZastPipeline::new().compile(src).is_success());
+    ///
+    /// let strict = ZastPipeline::with_config(ZastConfig::new().deny_warnings(true));
+    /// assert!(!strict.compile(src).is_success());
+    /// ```
+    pub fn is_success(&self) -> bool {
+        self.program.is_some() && !self.errors.has_errors()
+    }
+
+    /// Returns the program if every phase succeeded, or the collected errors
+    /// otherwise. Warnings are dropped on success; inspect
+    /// [`ZastCompilation::errors`] first to report them.
+    pub fn into_result(self) -> Result<ZastProgram, ZastErrorCollector> {
+        match self.program {
+            Some(program) if !self.errors.has_errors() => Ok(program),
+            _ => Err(self.errors),
+        }
+    }
+}
+
+/// Drives source text through the lexer, parser, and semantic analyzer.
+///
+/// Each phase only runs if the previous one succeeded. The returned
+/// [`ZastCompilation`] carries the errors of the furthest phase reached and,
+/// once parsing has succeeded, the AST itself.
+///
+/// # Example
+///
+/// ```rust
+/// use zast::pipeline::ZastPipeline;
+///
+/// let src = "fn main(): void { const unused = 1; }";
+/// let compilation = ZastPipeline::new().compile(src);
+///
+/// // prints errors, or warnings alongside a successful compilation
+/// compilation.errors.report_all_errors(src);
+/// assert!(compilation.is_success());
+/// ```
+pub struct ZastPipeline {
+    config: ZastConfig,
+    import_resolver: Option<ImportResolver>,
+}
+
+impl ZastPipeline {
+    pub fn new() -> Self {
+        Self::with_config(ZastConfig::default())
+    }
+
+    pub fn with_config(config: ZastConfig) -> Self {
+        Self {
+            config,
+            import_resolver: None,
+        }
+    }
+
+    pub fn set_import_resolver(&mut self, resolver: ImportResolver) {
+        self.import_resolver = Some(resolver);
+    }
+
+    /// Runs every phase over `src`, stopping at the first phase that fails.
+    pub fn compile(&self, src: &str) -> ZastCompilation {
+        let tokens = match ZastLexer::with_config(src, &self.config).tokenize() {
+            Ok(tokens) => tokens,
+            Err(errors) => {
+                return ZastCompilation {
+                    program: None,
+                    errors,
+                    phase: ZastPhase::Lexing,
+                };
+            }
+        };
+
+        let program = match ZastParser::with_config(tokens, &self.config).parse_program() {
+            Ok(program) => program,
+            Err(errors) => {
+                return ZastCompilation {
+                    program: None,
+                    errors,
+                    phase: ZastPhase::Parsing,
+                };
+            }
+        };
+
+        let mut sema = ZastSemanticAnalyzer::with_config(self.config.clone());
+        if let Some(resolver) = self.import_resolver {
+            sema.set_import_resolver(resolver);
+        }
+
+        let errors = match sema.analyze(&program) {
+            Ok(()) => sema.take_warnings(),
+            Err(errors) => errors,
+        };
+
+        ZastCompilation {
+            program: Some(program),
+            errors,
+            phase: ZastPhase::Analysis,
+        }
+    }
+
+    /// Runs every phase over `src`, returning the program only if all of them succeed.
+    pub fn run(&self, src: &str) -> Result<ZastProgram, ZastErrorCollector> {
+        self.compile(src).into_result()
+    }
+}
+
+impl Default for ZastPipeline {
+    fn default() -> Self {
+        Self::new()
+    }
+}
@@ -0,0 +1,25 @@
+//! Commonly used Zast types, re-exported for a single glob import.
+//!
+//! ```rust
+//! use zast::prelude::*;
+//!
+//! let tokens = ZastLexer::new("fn main(): void {}").tokenize();
+//! ```
+
+pub use crate::{
+    ast::{
+        Expr, Expression, FunctionParameter, Spanned, Statement, Stmt, Visitor, ZastProgram,
+        walk_program,
+    },
+    config::ZastConfig,
+    error_handler::{ZastErrorCollector, zast_errors::ZastError},
+    lexer::{
+        ZastLexer,
+        tokens::{Span, Token, TokenKind},
+    },
+    parser::ZastParser,
+    pipeline::ZastPipeline,
+    sema::{ZastSemanticAnalyzer, typed_program::TypedProgram},
+    source_map::SourceMap,
+    zast_ir::ZastIREmitter,
+};
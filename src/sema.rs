@@ -1,33 +1,127 @@
-use std::mem;
+use std::{
+    collections::{HashMap, HashSet},
+    mem,
+};
 
 use crate::{
-    ast::{Statement, Stmt, ZastProgram},
+    ast::{Argument, Expr, Expression, NodeId, Statement, Stmt, ZastProgram},
+    config::ZastConfig,
     error_handler::{ZastErrorCollector, zast_errors::ZastError},
-    lexer::tokens::Span,
-    sema::{symbol_type_table::ZastSymbolTypeTable, type_map::ZastTypeMap},
-    types::{ValueType, return_type},
+    lexer::tokens::{Span, TokenKind},
+    parser::ZastParser,
+    sema::{
+        symbol_type_table::{SymbolTypeScope, ZastSymbolTypeTable},
+        type_map::ZastTypeMap,
+        typed_program::TypedProgram,
+    },
+    types::{
+        FloatWidth, ValueType, annotated_type::AnnotatedType, const_expr::ConstExpr,
+        return_type::ReturnType,
+    },
 };
 
 pub mod symbol_type_table;
 pub mod type_map;
+pub mod typed_program;
+
+/// Resolves an import path to the source text of the imported module.
+///
+/// Returning `None` reports the import as unresolved. Keeping file loading in
+/// a user-supplied callback keeps all I/O out of the analyzer.
+pub type ImportResolver = fn(&str) -> Option<String>;
 
 #[derive(Debug)]
 pub struct ZastSemanticAnalyzer {
     pub(crate) errors: ZastErrorCollector,
     pub(crate) type_map: ZastTypeMap,
     pub(crate) symbol_type_table: ZastSymbolTypeTable,
+    pub(crate) import_resolver: Option<ImportResolver>,
+    pub(crate) imported_modules: HashSet<String>,
+    pub(crate) config: ZastConfig,
+    /// Return type of the function whose body is being analyzed, if any.
+    pub(crate) current_return_type: Option<ValueType>,
+    expr_types: HashMap<NodeId, ValueType>,
+    binding_types: HashMap<Span, ValueType>,
+    // values of `const` bindings that fold to an integer, keyed by declaration span
+    const_values: HashMap<Span, i64>,
+    // parameter names of declared functions, keyed by declaration span
+    function_params: HashMap<Span, Vec<String>>,
+    // top-level names brought in by imports, mapped to the import path
+    imported_names: HashMap<String, String>,
+    // span of the target repeated as the left operand of a desugared compound
+    // assignment, e.g. the second `x` in `x = x + 1` from `x += 1`
+    compound_target: Option<Span>,
 }
 
 impl ZastSemanticAnalyzer {
     pub fn new() -> Self {
+        Self::with_config(ZastConfig::default())
+    }
+
+    pub fn with_config(config: ZastConfig) -> Self {
         Self {
-            errors: ZastErrorCollector::new(),
+            errors: ZastErrorCollector::from_config(&config),
             type_map: ZastTypeMap::new(),
             symbol_type_table: ZastSymbolTypeTable::new(),
+            import_resolver: None,
+            imported_modules: HashSet::new(),
+            config,
+            current_return_type: None,
+            expr_types: HashMap::new(),
+            binding_types: HashMap::new(),
+            const_values: HashMap::new(),
+            function_params: HashMap::new(),
+            imported_names: HashMap::new(),
+            compound_target: None,
         }
     }
 
-    pub fn analyze(&mut self, program: ZastProgram) -> Result<(), ZastErrorCollector> {
+    /// Installs the callback that loads the source text of an imported path.
+    /// Without one, or when it returns `None`, imports are reported as
+    /// unresolved.
+    ///
+    /// ```rust
+    /// use zast::{parser::ZastParser, sema::ZastSemanticAnalyzer};
+    ///
+    /// fn resolve(path: &str) -> Option<String> {
+    ///     (path == "math.zast").then(|| String::from("fn square(x: i32): i32 { return x * x; }"))
+    /// }
+    ///
+    /// let src = "import \"math.zast\";\nfn f(): i32 { return square(3); }";
+    /// let mut analyzer = ZastSemanticAnalyzer::new();
+    /// analyzer.set_import_resolver(resolve);
+    /// assert!(analyzer.analyze(&ZastParser::from_source(src).unwrap()).is_ok());
+    /// assert!(analyzer.lookup_function("square").is_some());
+    ///
+    /// let src = "import \"missing.zast\";";
+    /// let mut analyzer = ZastSemanticAnalyzer::new();
+    /// analyzer.set_import_resolver(resolve);
+    /// let errors = analyzer
+    ///     .analyze(&ZastParser::from_source(src).unwrap())
+    ///     .unwrap_err();
+    /// assert_eq!(errors.errors()[0].get_error_code(), "E0010");
+    /// ```
+    pub fn set_import_resolver(&mut self, resolver: ImportResolver) {
+        self.import_resolver = Some(resolver);
+    }
+
+    /// Analyzes `program`, returning all diagnostics if any of them is an
+    /// error. On success, any warnings remain available through
+    /// [`ZastSemanticAnalyzer::take_warnings`].
+    ///
+    /// Functions can only be declared at the top level; a `fn` inside another
+    /// function's body is rejected with [`ZastError::NestedFunction`].
+    ///
+    /// ```rust
+    /// use zast::{parser::ZastParser, sema::ZastSemanticAnalyzer};
+    ///
+    /// let src = "fn outer(): void { fn inner(): void {} }";
+    /// let program = ZastParser::from_source(src).unwrap();
+    /// let errors = ZastSemanticAnalyzer::new().analyze(&program).unwrap_err();
+    ///
+    /// assert_eq!(errors.errors()[0].get_error_code(), "E0042");
+    /// ```
+    pub fn analyze(&mut self, program: &ZastProgram) -> Result<(), ZastErrorCollector> {
         for stmt in &program.body {
             let _ = self.analyze_stmt(stmt);
         }
@@ -39,6 +133,94 @@ impl ZastSemanticAnalyzer {
         }
     }
 
+    /// Like [`ZastSemanticAnalyzer::analyze`], but on success hands `program`
+    /// back as a [`TypedProgram`] carrying the inferred type of every
+    /// expression, so later stages don't have to re-derive them.
+    pub fn analyze_typed(
+        &mut self,
+        program: ZastProgram,
+    ) -> Result<TypedProgram, ZastErrorCollector> {
+        self.analyze(&program)?;
+        Ok(TypedProgram::new(
+            program,
+            mem::take(&mut self.expr_types),
+            mem::take(&mut self.binding_types),
+        ))
+    }
+
+    /// Returns the type of the top-level function `name`, always a
+    /// [`ValueType::Function`], or `None` if no such function was declared.
+    /// Meant to be called after [`ZastSemanticAnalyzer::analyze`].
+    ///
+    /// ```rust
+    /// use zast::{parser::ZastParser, sema::ZastSemanticAnalyzer};
+    ///
+    /// let src = "fn add(a: i32, b: i32): i32 { a + b } fn main(): void { const x: i32 = 1; }";
+    /// let mut analyzer = ZastSemanticAnalyzer::new();
+    /// let _ = analyzer.analyze(&ZastParser::from_source(src).unwrap());
+    ///
+    /// let add = analyzer.lookup_function("add").unwrap();
+    /// assert_eq!(add.to_string(), "fn(i32, i32): i32");
+    /// // locals and unknown names are not functions
+    /// assert!(analyzer.lookup_function("x").is_none());
+    /// assert!(analyzer.lookup_function("sub").is_none());
+    /// ```
+    pub fn lookup_function(&self, name: &str) -> Option<&ValueType> {
+        self.symbol_type_table
+            .global_scope()
+            .lookup(name)
+            .map(|symbol| symbol.value_type())
+            .filter(|value_type| matches!(value_type, ValueType::Function { .. }))
+    }
+
+    /// Returns a snapshot of every top-level declaration, functions and
+    /// globals alike, mapping its name to its type and declaration span.
+    /// Symbols merged in through imports are included.
+    ///
+    /// ```rust
+    /// use zast::{parser::ZastParser, sema::ZastSemanticAnalyzer};
+    ///
+    /// let src = "fn f(): void {}\nfn g(a: i32): i32 { a }";
+    /// let mut analyzer = ZastSemanticAnalyzer::new();
+    /// analyzer.analyze(&ZastParser::from_source(src).unwrap()).unwrap();
+    ///
+    /// let symbols = analyzer.global_symbols();
+    /// assert_eq!(symbols.len(), 2);
+    ///
+    /// let (g_type, g_span) = &symbols["g"];
+    /// assert_eq!(g_type.to_string(), "fn(i32): i32");
+    /// assert_eq!((g_span.ln_start, g_span.col_start), (2, 1));
+    /// assert_eq!(symbols["f"].1.ln_start, 1);
+    /// ```
+    pub fn global_symbols(&self) -> HashMap<String, (ValueType, Span)> {
+        self.symbol_type_table
+            .global_scope()
+            .symbols()
+            .map(|(name, symbol)| (name.clone(), (symbol.value_type().clone(), symbol.span())))
+            .collect()
+    }
+
+    /// Takes the warnings collected by a successful [`ZastSemanticAnalyzer::analyze`].
+    ///
+    /// ```rust
+    /// use zast::{
+    ///     error_handler::zast_errors::ZastError, parser::ZastParser, sema::ZastSemanticAnalyzer,
+    /// };
+    ///
+    /// let src = "fn f(): i32 { const unused = 1; const used = 2; return used; }";
+    /// let mut analyzer = ZastSemanticAnalyzer::new();
+    /// analyzer.analyze(&ZastParser::from_source(src).unwrap()).unwrap();
+    ///
+    /// let warnings = analyzer.take_warnings();
+    /// assert!(matches!(
+    ///     warnings.errors(),
+    ///     [ZastError::UnusedVariable { variable_name, .. }] if variable_name == "unused"
+    /// ));
+    /// ```
+    pub fn take_warnings(&mut self) -> ZastErrorCollector {
+        mem::take(&mut self.errors)
+    }
+
     fn analyze_stmt(&mut self, stmt: &Statement) -> Option<()> {
         match &stmt.node {
             Stmt::FunctionDeclaration {
@@ -47,42 +229,1237 @@ impl ZastSemanticAnalyzer {
                 return_type,
                 body,
             } => {
+                // the IR has no closures, so functions only live at the top level
+                if self.current_return_type.is_some() {
+                    self.throw_error(ZastError::NestedFunction {
+                        span: stmt.span,
+                        fn_name: name.clone(),
+                    });
+                    return None;
+                }
+
                 let mut params = Vec::new();
 
                 for param in parameters {
-                    params.push(ValueType::from_annotated_type(param.annotated_type.clone()));
+                    params.push(self.resolve_annotated_type(&param.annotated_type, param.span)?);
                 }
 
+                let return_type = match return_type {
+                    ReturnType::Void => ValueType::Void,
+                    ReturnType::Type(t) => self.resolve_annotated_type(t, stmt.span)?,
+                };
+
                 self.declare_function_type(
                     name.clone(),
-                    params,
-                    ValueType::from_return_type(return_type.clone()),
+                    params.clone(),
+                    return_type.clone(),
+                    stmt.span,
+                );
+                self.function_params.insert(
                     stmt.span,
+                    parameters.iter().map(|param| param.name.clone()).collect(),
                 );
 
                 self.enter_scope();
-                for param in parameters {
+                for (i, (param, value_type)) in parameters.iter().zip(params).enumerate() {
+                    if let Some(original) = parameters[..i].iter().find(|p| p.name == param.name) {
+                        self.throw_error(ZastError::DuplicateParameter {
+                            span: param.span,
+                            name: param.name.clone(),
+                            original_span: original.span,
+                        });
+                        continue;
+                    }
+
                     self.declare_ident_type_mapping(
                         param.name.clone(),
-                        ValueType::from_annotated_type(param.annotated_type.clone()),
+                        value_type,
+                        false,
                         param.span,
                     );
                 }
 
-                self.analyze_stmt(body.as_ref())?;
+                let must_return = return_type != ValueType::Void;
+                let enclosing_return_type = self.current_return_type.replace(return_type.clone());
+                // the body shares the parameters' scope, so a local can't shadow one
+                let _ = match &body.node {
+                    Stmt::BlockStatement { statements, tail } => {
+                        self.analyze_block(statements, tail.as_deref(), Some(&return_type))
+                    }
+                    _ => self.analyze_stmt(body.as_ref()),
+                };
+                self.current_return_type = enclosing_return_type;
                 self.exit_scope();
 
+                if must_return && !Self::returns_on_all_paths(body) {
+                    self.throw_error(ZastError::MissingReturn {
+                        span: stmt.span,
+                        fn_name: name.clone(),
+                    });
+                }
+
+                Some(())
+            }
+
+            Stmt::BlockStatement { statements, tail } => {
+                self.enter_scope();
+                let result = self.analyze_block(statements, tail.as_deref(), None);
+                self.exit_scope();
+                result
+            }
+            Stmt::Expression { expression } => {
+                self.infer_expr_type(expression)?;
+                Some(())
+            }
+
+            Stmt::VariableDeclaration {
+                mutable,
+                identifier,
+                annotated_type,
+                value,
+            } => {
+                let value_type = match annotated_type {
+                    Some(annotated_type) => {
+                        let value_type = self.resolve_annotated_type(annotated_type, stmt.span)?;
+                        self.check_expr_type(value, &value_type)?;
+                        value_type
+                    }
+                    None => self.infer_expr_type(value)?,
+                };
+
+                if !*mutable
+                    && matches!(value_type, ValueType::Integer { .. })
+                    && let Some(value) = ConstExpr::from_expr(value)
+                        .and_then(|expr| expr.eval(&mut |name| self.const_value(name)))
+                {
+                    self.const_values.insert(stmt.span, value);
+                }
+
+                self.declare_ident_type_mapping(identifier.clone(), value_type, *mutable, stmt.span)
+            }
+
+            Stmt::Import { path } => self.analyze_import(path, stmt.span),
+
+            Stmt::Return { value } => {
+                let Some(return_type) = self.current_return_type.clone() else {
+                    self.throw_error(ZastError::ReturnOutsideFunction { span: stmt.span });
+                    return None;
+                };
+
+                match value {
+                    Some(value) => self.check_expr_type(value, &return_type),
+                    None if return_type == ValueType::Void => Some(()),
+                    None => {
+                        self.throw_error(ZastError::TypeMismatch {
+                            span: stmt.span,
+                            expected: return_type,
+                            found: ValueType::Void,
+                        });
+                        None
+                    }
+                }
+            }
+
+            Stmt::If {
+                condition,
+                then_branch,
+                else_branch,
+            } => {
+                self.check_expr_type(condition, &ValueType::Bool)?;
+                self.analyze_stmt(then_branch)?;
+                if let Some(else_branch) = else_branch {
+                    self.analyze_stmt(else_branch)?;
+                }
                 Some(())
             }
 
-            Stmt::BlockStatement { statements } => {
-                for stmt in statements {
-                    self.analyze_stmt(stmt.as_ref())?;
+            Stmt::While { condition, body } => {
+                self.check_expr_type(condition, &ValueType::Bool)?;
+                self.analyze_stmt(body)
+            }
+
+            Stmt::StructDeclaration { name, fields } => {
+                let struct_type = AnnotatedType::Primitive(name.clone());
+
+                if self.type_map.resolve_mapping(&struct_type).is_some() {
+                    self.throw_error(ZastError::TypeRedeclaration {
+                        span: stmt.span,
+                        type_name: name.clone(),
+                    });
+                    return None;
+                }
+
+                let mut resolved_fields = Vec::new();
+                for (i, field) in fields.iter().enumerate() {
+                    if let Some(original) = fields[..i].iter().find(|f| f.name == field.name) {
+                        self.throw_error(ZastError::DuplicateField {
+                            span: field.span,
+                            name: field.name.clone(),
+                            original_span: original.span,
+                        });
+                        continue;
+                    }
+
+                    let field_type =
+                        self.resolve_annotated_type(&field.annotated_type, field.span)?;
+                    resolved_fields.push((field.name.clone(), field_type));
                 }
 
+                self.type_map.add_mapping(
+                    struct_type,
+                    ValueType::Struct {
+                        name: name.clone(),
+                        fields: resolved_fields,
+                    },
+                );
                 Some(())
             }
-            e => todo!("{:#?}", e),
+
+            Stmt::EnumDeclaration { name, variants } => {
+                let enum_type = AnnotatedType::Primitive(name.clone());
+
+                if self.type_map.resolve_mapping(&enum_type).is_some() {
+                    self.throw_error(ZastError::TypeRedeclaration {
+                        span: stmt.span,
+                        type_name: name.clone(),
+                    });
+                    return None;
+                }
+
+                self.type_map.add_mapping(
+                    enum_type,
+                    ValueType::Enum {
+                        name: name.clone(),
+                        variants: variants.clone(),
+                    },
+                );
+                Some(())
+            }
+        }
+    }
+
+    fn infer_expr_type(&mut self, expr: &Expression) -> Option<ValueType> {
+        let value_type = self.compute_expr_type(expr)?;
+        self.expr_types.insert(expr.id, value_type.clone());
+        Some(value_type)
+    }
+
+    /// Records that the numeric literal `expr` adapted to `value_type`,
+    /// including the literal under a negation.
+    fn record_literal_type(&mut self, expr: &Expression, value_type: &ValueType) {
+        self.expr_types.insert(expr.id, value_type.clone());
+        if let Expr::Negate(operand) = &expr.node {
+            self.record_literal_type(operand, value_type);
+        }
+    }
+
+    fn compute_expr_type(&mut self, expr: &Expression) -> Option<ValueType> {
+        match &expr.node {
+            Expr::IntegerLiteral {
+                value,
+                suffix: Some(suffix),
+            } => self.check_int_suffix(*value, suffix, expr.span),
+            Expr::IntegerLiteral { suffix: None, .. } => Some(self.config.default_int_type.clone()),
+            Expr::FloatLiteral {
+                suffix: Some(suffix),
+                ..
+            } => self.resolve_annotated_type(suffix, expr.span),
+            Expr::FloatLiteral { suffix: None, .. } => Some(ValueType::Float {
+                width: FloatWidth::F64,
+            }),
+            Expr::CharLiteral(_) => Some(ValueType::Integer {
+                bits: 8,
+                unsigned: true,
+            }),
+            // a C-style string: a pointer to its first byte
+            Expr::StringLiteral(_) => Some(ValueType::Pointer(Box::new(ValueType::Integer {
+                bits: 8,
+                unsigned: true,
+            }))),
+            Expr::Identifier(name) => self.resolve_ident_type(name, expr.span),
+            Expr::Address(operand) => {
+                let pointee = self.infer_expr_type(operand)?;
+                Some(ValueType::Pointer(Box::new(pointee)))
+            }
+            Expr::Negate(operand) => {
+                // range-check `-128i8` as a whole rather than `128i8` alone
+                if let Expr::IntegerLiteral {
+                    value,
+                    suffix: Some(suffix),
+                } = &operand.node
+                {
+                    return self.check_int_suffix(value.checked_neg()?, suffix, expr.span);
+                }
+
+                match self.infer_expr_type(operand)? {
+                    numeric @ (ValueType::Integer { .. } | ValueType::Float { .. }) => {
+                        Some(numeric)
+                    }
+                    found => {
+                        self.throw_error(ZastError::InvalidNegation {
+                            span: expr.span,
+                            found,
+                        });
+                        None
+                    }
+                }
+            }
+            Expr::Cast {
+                expr: operand,
+                target,
+            } => {
+                let from = self.infer_expr_type(operand)?;
+                let to = self.resolve_annotated_type(target, expr.span)?;
+
+                if !Self::is_valid_cast(&from, &to) {
+                    self.throw_error(ZastError::InvalidCast {
+                        span: expr.span,
+                        from,
+                        to,
+                    });
+                    return None;
+                }
+                Some(to)
+            }
+            Expr::Dereference(operand) => match self.infer_expr_type(operand)? {
+                ValueType::Pointer(pointee) => Some(*pointee),
+                found => {
+                    self.throw_error(ZastError::InvalidDereference {
+                        span: expr.span,
+                        found,
+                    });
+                    None
+                }
+            },
+            Expr::BinaryExpression {
+                left,
+                operator,
+                right,
+            } => self.infer_binary_type(expr.span, left, *operator, right),
+            Expr::Index { base, index } => {
+                let base_type = self.infer_expr_type(base)?;
+                let index_type = self.infer_expr_type(index)?;
+
+                if !matches!(index_type, ValueType::Integer { .. }) {
+                    self.throw_error(ZastError::NonIntegerIndex {
+                        span: index.span,
+                        found: index_type,
+                    });
+                    return None;
+                }
+
+                match base_type {
+                    ValueType::Array { element, .. } => Some(*element),
+                    ValueType::Pointer(pointee) => Some(*pointee),
+                    found => {
+                        self.throw_error(ZastError::NotIndexable {
+                            span: base.span,
+                            found,
+                        });
+                        None
+                    }
+                }
+            }
+            Expr::Assignment { target, value } => {
+                let target_type = self.analyze_assignment_target(target)?;
+
+                // `x += 1` only writes `x`, even though it reads back as `x = x + 1`
+                let compound_target = match &value.node {
+                    Expr::BinaryExpression { left, .. } if left.span == target.span => {
+                        Some(left.span)
+                    }
+                    _ => None,
+                };
+                let enclosing = mem::replace(&mut self.compound_target, compound_target);
+                let checked = self.check_expr_type(value, &target_type);
+                self.compound_target = enclosing;
+
+                checked?;
+                Some(target_type)
+            }
+            Expr::Ternary {
+                condition,
+                then_expr,
+                else_expr,
+            } => self.infer_ternary_type(condition, then_expr, else_expr),
+            Expr::FieldAccess { base, field } => {
+                let base_type = self.infer_expr_type(base)?;
+
+                if let ValueType::Struct { fields, .. } = &base_type
+                    && let Some((_, field_type)) = fields.iter().find(|(name, _)| name == field)
+                {
+                    return Some(field_type.clone());
+                }
+
+                self.throw_error(ZastError::UnknownField {
+                    span: expr.span,
+                    found: base_type,
+                    field_name: field.clone(),
+                });
+                None
+            }
+            Expr::EnumVariant { enum_name, variant } => {
+                let enum_type = AnnotatedType::Primitive(enum_name.clone());
+                let value_type = self.resolve_annotated_type(&enum_type, expr.span)?;
+
+                if let ValueType::Enum { variants, .. } = &value_type
+                    && variants.contains(variant)
+                {
+                    return Some(value_type);
+                }
+
+                self.throw_error(ZastError::UnknownEnumVariant {
+                    span: expr.span,
+                    enum_name: enum_name.clone(),
+                    variant: variant.clone(),
+                });
+                None
+            }
+            Expr::Call { callee, arguments } => {
+                let (params, return_type) = match self.infer_expr_type(callee)? {
+                    ValueType::Function {
+                        params,
+                        return_type,
+                    } => (params, return_type),
+                    found => {
+                        self.throw_error(ZastError::NotCallable {
+                            span: callee.span,
+                            found,
+                        });
+                        return None;
+                    }
+                };
+
+                if params.len() != arguments.len() {
+                    self.throw_error(ZastError::ArgumentCountMismatch {
+                        span: expr.span,
+                        expected: params.len(),
+                        found: arguments.len(),
+                    });
+                    return None;
+                }
+
+                let param_names = self.callee_param_names(callee);
+                let order = self.bind_arguments(arguments, param_names.as_deref())?;
+                for (argument, index) in arguments.iter().zip(order) {
+                    self.check_expr_type(&argument.value, &params[index])?;
+                }
+
+                Some(*return_type)
+            }
+        }
+    }
+
+    /// Types `condition ? then_expr : else_expr`: the condition must be a
+    /// `bool` and the else-branch must match the then-branch, whose type is
+    /// the result.
+    ///
+    /// ```rust
+    /// use zast::pipeline::ZastPipeline;
+    ///
+    /// let src = "fn f(a: bool, c: bool): i32 { a ? 1 : c ? 2 : 3 }";
+    /// assert!(ZastPipeline::new().compile(src).is_success());
+    ///
+    /// for src in [
+    ///     "fn f(a: i32): i32 { a ? 1 : 2 }",
+    ///     "fn f(a: bool): i32 { a ? 1 : a }",
+    /// ] {
+    ///     let errors = ZastPipeline::new().compile(src).errors;
+    ///     assert_eq!(errors.errors()[0].get_error_code(), "E0008");
+    /// }
+    /// ```
+    fn infer_ternary_type(
+        &mut self,
+        condition: &Expression,
+        then_expr: &Expression,
+        else_expr: &Expression,
+    ) -> Option<ValueType> {
+        self.check_expr_type(condition, &ValueType::Bool)?;
+        let then_type = self.infer_expr_type(then_expr)?;
+        self.check_expr_type(else_expr, &then_type)?;
+        Some(then_type)
+    }
+
+    /// Returns the parameter names of the function `callee` refers to, or
+    /// `None` if it isn't a direct reference to a declared function, e.g. a
+    /// function-typed parameter.
+    fn callee_param_names(&mut self, callee: &Expression) -> Option<Vec<String>> {
+        let Expr::Identifier(name) = &callee.node else {
+            return None;
+        };
+
+        let span = self.symbol_type_table.resolve_ident_type(name)?.span();
+        self.function_params.get(&span).cloned()
+    }
+
+    /// Returns, for each argument, the index of the parameter it fills:
+    /// positional arguments fill parameters in order, named ones the
+    /// parameter of that name. Expects as many arguments as parameters.
+    ///
+    /// Positional arguments may not follow named ones, and no parameter may
+    /// be filled twice. Naming an argument requires `param_names`.
+    ///
+    /// ```rust
+    /// use zast::pipeline::ZastPipeline;
+    ///
+    /// let codes = |call: &str| {
+    ///     let src = format!(
+    ///         "fn sub(a: i32, b: i32): i32 {{ return a - b; }}
+    ///          fn main(): i32 {{ return {}; }}",
+    ///         call
+    ///     );
+    ///     let errors = ZastPipeline::new().compile(&src).errors;
+    ///     errors.errors().iter().map(|e| e.get_error_code()).collect::<Vec<_>>()
+    /// };
+    ///
+    /// assert!(codes("sub(b: 1, a: 2)").is_empty());
+    /// assert!(codes("sub(1, b: 2)").is_empty());
+    /// assert_eq!(codes("sub(a: 1, c: 2)"), ["E0033"]);
+    /// assert_eq!(codes("sub(a: 1, a: 2)"), ["E0034"]);
+    /// assert_eq!(codes("sub(a: 1, 2)"), ["E0035"]);
+    /// ```
+    fn bind_arguments(
+        &mut self,
+        arguments: &[Argument],
+        param_names: Option<&[String]>,
+    ) -> Option<Vec<usize>> {
+        let mut filled = vec![false; arguments.len()];
+        let mut order = Vec::new();
+        let mut seen_named = false;
+
+        for (position, argument) in arguments.iter().enumerate() {
+            let index = match &argument.name {
+                None if seen_named => {
+                    self.throw_error(ZastError::PositionalAfterNamed {
+                        span: argument.span,
+                    });
+                    continue;
+                }
+                None => position,
+                Some(name) => {
+                    seen_named = true;
+                    let index =
+                        param_names.and_then(|names| names.iter().position(|param| param == name));
+                    let Some(index) = index else {
+                        self.throw_error(ZastError::UnknownArgumentName {
+                            span: argument.span,
+                            name: name.clone(),
+                        });
+                        continue;
+                    };
+                    index
+                }
+            };
+
+            if filled[index] {
+                self.throw_error(ZastError::DuplicateArgument {
+                    span: argument.span,
+                    name: argument.name.clone().unwrap_or_default(),
+                });
+                continue;
+            }
+
+            filled[index] = true;
+            order.push(index);
+        }
+
+        (order.len() == arguments.len()).then_some(order)
+    }
+
+    /// Checks that `target` is a place that can be assigned to and returns its
+    /// type. Assigning to a `const` binding or a parameter, directly or
+    /// through one of its fields or elements, is rejected.
+    ///
+    /// ```rust
+    /// use zast::pipeline::ZastPipeline;
+    ///
+    /// let errors = ZastPipeline::new()
+    ///     .compile("fn g(a: [i32; 2]): void { a[0] = 3; }")
+    ///     .errors;
+    /// assert_eq!(errors.errors()[0].get_error_code(), "E0019");
+    ///
+    /// let src = "fn f(a: [i32; 2]): void { const xs = a; xs[0] = 3; }";
+    /// let errors = ZastPipeline::new().compile(src).errors;
+    /// assert_eq!(errors.errors()[0].get_error_code(), "E0019");
+    ///
+    /// let src = "fn f(a: [i32; 2]): i32 { let xs = a; xs[0] = 3; return xs[0]; }";
+    /// assert!(ZastPipeline::new().compile(src).is_success());
+    /// ```
+    fn analyze_assignment_target(&mut self, target: &Expression) -> Option<ValueType> {
+        match &target.node {
+            Expr::Identifier(name) => {
+                self.check_binding_mutable(name, target.span)?;
+                self.symbol_type_table.mark_write(name);
+                self.symbol_type_table
+                    .resolve_ident_type(name)
+                    .map(|symbol| symbol.value_type.clone())
+            }
+            Expr::FieldAccess { .. } | Expr::Index { .. } => {
+                let mut root = target;
+                while let Expr::FieldAccess { base, .. } | Expr::Index { base, .. } = &root.node {
+                    root = base;
+                }
+
+                if let Expr::Identifier(name) = &root.node {
+                    self.check_binding_mutable(name, target.span)?;
+                }
+
+                self.infer_expr_type(target)
+            }
+            Expr::Dereference(_) => self.infer_expr_type(target),
+            _ => {
+                self.throw_error(ZastError::InvalidAssignmentTarget { span: target.span });
+                None
+            }
+        }
+    }
+
+    fn check_binding_mutable(&mut self, identifier: &str, span: Span) -> Option<()> {
+        match self.symbol_type_table.resolve_ident_type(identifier) {
+            Some(symbol) if symbol.mutable => Some(()),
+            Some(_) => {
+                self.throw_error(ZastError::AssignToImmutable {
+                    span,
+                    variable_name: identifier.to_string(),
+                });
+                None
+            }
+            None => {
+                self.throw_error(ZastError::UndeclaredVariable {
+                    span,
+                    variable_name: identifier.to_string(),
+                });
+                None
+            }
+        }
+    }
+
+    /// Analyzes the statements and tail of a block in the current scope.
+    ///
+    /// Code following a `return` in the same block is reported as unreachable
+    /// once, at the first dead statement. A `return` nested in an `if` leaves
+    /// the code after the `if` reachable.
+    ///
+    /// For a function body, `tail_type` is its return type and the tail is
+    /// checked against it like a `return` value would be.
+    ///
+    /// ```rust
+    /// use zast::pipeline::ZastPipeline;
+    ///
+    /// let src = "fn f(): i32 {\n    return 1;\n    const x = 2;\n    const y = 3;\n}";
+    /// let errors = ZastPipeline::new().compile(src).errors;
+    /// let unreachable: Vec<_> = errors
+    ///     .errors()
+    ///     .iter()
+    ///     .filter(|error| error.get_error_code() == "E0026")
+    ///     .collect();
+    /// assert_eq!(unreachable.len(), 1);
+    /// assert_eq!(unreachable[0].get_span().ln_start, 3);
+    ///
+    /// let src = "fn f(c: bool): i32 { if c { return 1; } return 2; }";
+    /// assert!(ZastPipeline::new().compile(src).errors.errors().is_empty());
+    ///
+    /// let codes = |src: &str| {
+    ///     let errors = ZastPipeline::new().compile(src).errors;
+    ///     errors.errors().iter().map(|e| e.get_error_code()).collect::<Vec<_>>()
+    /// };
+    /// assert!(codes("fn f(): u8 { 200 }").is_empty());
+    /// assert_eq!(codes("fn f(): u8 { 300 }"), ["E0021"]);
+    /// assert_eq!(codes("fn f(): i32 { 1.5 }"), ["E0008"]);
+    /// assert_eq!(codes("fn f(): void { 1 }"), ["E0008"]);
+    /// ```
+    fn analyze_block(
+        &mut self,
+        statements: &[Box<Statement>],
+        tail: Option<&Expression>,
+        tail_type: Option<&ValueType>,
+    ) -> Option<()> {
+        let mut diverged = false;
+
+        for stmt in statements {
+            if diverged {
+                self.throw_error(ZastError::UnreachableCode { span: stmt.span });
+                diverged = false;
+            } else if Self::stmt_diverges(stmt) {
+                diverged = true;
+            }
+
+            self.analyze_stmt(stmt.as_ref())?;
+        }
+
+        if let Some(tail) = tail {
+            if diverged {
+                self.throw_error(ZastError::UnreachableCode { span: tail.span });
+            }
+
+            match tail_type {
+                Some(tail_type) => self.check_expr_type(tail, tail_type)?,
+                None => {
+                    self.infer_expr_type(tail)?;
+                }
+            }
+        }
+
+        Some(())
+    }
+
+    /// Returns `true` if every path through the function body `body` ends in
+    /// a `return`, or the body ends in a tail expression that yields its value.
+    ///
+    /// An `if` only counts when it has an `else` and both branches return; a
+    /// `while` never does, since its body may not run.
+    ///
+    /// ```rust
+    /// use zast::pipeline::ZastPipeline;
+    ///
+    /// let codes = |body: &str| {
+    ///     let src = format!("fn f(a: i32, b: i32): i32 {{ {} }}", body);
+    ///     let errors = ZastPipeline::new().compile(&src).errors;
+    ///     errors.errors().iter().map(|e| e.get_error_code()).collect::<Vec<_>>()
+    /// };
+    ///
+    /// assert!(codes("if a < b { return a; } else { return b; }").is_empty());
+    /// assert_eq!(codes("if a < b { return a; }"), ["E0036"]);
+    /// assert_eq!(codes("while a < b { return a; }"), ["E0036"]);
+    /// ```
+    fn returns_on_all_paths(body: &Statement) -> bool {
+        match &body.node {
+            Stmt::BlockStatement { tail: Some(_), .. } => true,
+            _ => Self::stmt_diverges(body),
+        }
+    }
+
+    /// Returns `true` if control never continues past `stmt`: a `return`, or a
+    /// block that unconditionally reaches one.
+    fn stmt_diverges(stmt: &Statement) -> bool {
+        match &stmt.node {
+            Stmt::Return { .. } => true,
+            Stmt::BlockStatement { statements, .. } => {
+                statements.iter().any(|stmt| Self::stmt_diverges(stmt))
+            }
+            // an if only diverges when every branch does
+            Stmt::If {
+                then_branch,
+                else_branch: Some(else_branch),
+                ..
+            } => Self::stmt_diverges(then_branch) && Self::stmt_diverges(else_branch),
+            _ => false,
+        }
+    }
+
+    /// Infers the result type of `left <operator> right`.
+    ///
+    /// Arithmetic and ordering operators require both operands to have the
+    /// same numeric type, equality accepts any matching pair, and logical
+    /// operators require `bool`. Comparisons always yield `bool`. For `**`, an
+    /// integer base takes an integer exponent and a float base takes either.
+    /// Bitwise `&`, `^`, and `|` require the same integer type, and shifts take
+    /// any integer shift amount, yielding the type of the shifted value.
+    ///
+    /// ```rust
+    /// use zast::pipeline::ZastPipeline;
+    ///
+    /// let src = "fn f(a: i32, b: i32): bool { const sum: i32 = a + b; return sum < b; }";
+    /// assert!(ZastPipeline::new().compile(src).is_success());
+    ///
+    /// for src in [
+    ///     "fn f(a: i32, b: f64): void { const x = a + b; }",
+    ///     "fn f(a: bool, b: i32): void { const x = a + b; }",
+    /// ] {
+    ///     let errors = ZastPipeline::new().compile(src).errors;
+    ///     assert_eq!(errors.errors()[0].get_error_code(), "E0024");
+    /// }
+    /// ```
+    ///
+    /// ```rust
+    /// use zast::pipeline::ZastPipeline;
+    ///
+    /// assert!(ZastPipeline::new().compile("const x = 6 & 3 | 1 << 4 ^ 5;").is_success());
+    /// assert!(ZastPipeline::new().compile("const p = 2.0 ** 3;").is_success());
+    /// for src in ["const y = 1.5 | 2.0;", "const z = 1.5 ^ 2.0;"] {
+    ///     let errors = ZastPipeline::new().compile(src).errors;
+    ///     assert_eq!(errors.errors()[0].get_error_code(), "E0024");
+    /// }
+    /// ```
+    ///
+    /// A literal operand adapts to the type of a non-literal one, so `x + 1`
+    /// is accepted for `x: u8`.
+    fn infer_binary_type(
+        &mut self,
+        span: Span,
+        left: &Expression,
+        operator: TokenKind,
+        right: &Expression,
+    ) -> Option<ValueType> {
+        let mut left_type = self.infer_expr_type(left)?;
+        let mut right_type = self.infer_expr_type(right)?;
+
+        if operator != TokenKind::DoubleStar {
+            if Self::is_numeric_literal(left) && !Self::is_numeric_literal(right) {
+                left_type = self.adapt_literal_operand(left, left_type, &right_type)?;
+            } else if Self::is_numeric_literal(right) && !Self::is_numeric_literal(left) {
+                right_type = self.adapt_literal_operand(right, right_type, &left_type)?;
+            }
+        }
+
+        let is_numeric =
+            |t: &ValueType| matches!(t, ValueType::Integer { .. } | ValueType::Float { .. });
+
+        let result = match operator {
+            TokenKind::Plus | TokenKind::Minus | TokenKind::Multiply | TokenKind::Divide
+                if left_type == right_type && is_numeric(&left_type) =>
+            {
+                Some(left_type.clone())
+            }
+            TokenKind::DoubleStar => match (&left_type, &right_type) {
+                (ValueType::Integer { .. }, ValueType::Integer { .. })
+                | (ValueType::Float { .. }, ValueType::Integer { .. } | ValueType::Float { .. }) => {
+                    Some(left_type.clone())
+                }
+                _ => None,
+            },
+            TokenKind::Ampersand | TokenKind::Caret | TokenKind::Pipe
+                if left_type == right_type && matches!(left_type, ValueType::Integer { .. }) =>
+            {
+                Some(left_type.clone())
+            }
+            TokenKind::Shl | TokenKind::Shr => match (&left_type, &right_type) {
+                (ValueType::Integer { .. }, ValueType::Integer { .. }) => Some(left_type.clone()),
+                _ => None,
+            },
+            TokenKind::Equal | TokenKind::NotEqual if left_type == right_type => {
+                Some(ValueType::Bool)
+            }
+            TokenKind::Less
+            | TokenKind::LessEqual
+            | TokenKind::Greater
+            | TokenKind::GreaterEqual
+                if left_type == right_type && is_numeric(&left_type) =>
+            {
+                Some(ValueType::Bool)
+            }
+            TokenKind::LogicalAnd | TokenKind::LogicalOr
+                if left_type == ValueType::Bool && right_type == ValueType::Bool =>
+            {
+                Some(ValueType::Bool)
+            }
+            _ => None,
+        };
+
+        if result.is_none() {
+            self.throw_error(ZastError::BinaryTypeMismatch {
+                span,
+                op: operator,
+                left: left_type,
+                right: right_type,
+            });
+        }
+
+        result
+    }
+
+    /// Gives a literal operand the type of the other operand when the literal
+    /// is of the same kind, range-checking integer literals on the way.
+    fn adapt_literal_operand(
+        &mut self,
+        literal: &Expression,
+        literal_type: ValueType,
+        other_type: &ValueType,
+    ) -> Option<ValueType> {
+        let adapts = match other_type {
+            ValueType::Integer { .. } => Self::int_literal_value(literal).is_some(),
+            ValueType::Float { .. } => Self::is_float_literal(literal),
+            _ => false,
+        };
+
+        if !adapts {
+            return Some(literal_type);
+        }
+
+        self.check_expr_type(literal, other_type)?;
+        Some(other_type.clone())
+    }
+
+    /// Checks that `value` can be stored in a binding of type `expected`.
+    ///
+    /// Numeric literals adapt to any type of their own kind, so
+    /// `let x: u8 = 5;` is accepted even though `5` alone infers as `i32`.
+    /// Integer literals must still fit the range of the expected type.
+    ///
+    /// There is no implicit conversion between integers and floats in either
+    /// direction, for literals or otherwise: `let y: f32 = 5;` is a mismatch.
+    ///
+    /// ```rust
+    /// use zast::pipeline::ZastPipeline;
+    ///
+    /// let mismatches = [
+    ///     ("const x: i32 = 3.14;", "expected 'i32', found 'f64'"),
+    ///     ("const y: f32 = 5;", "expected 'f32', found 'i32'"),
+    ///     ("fn f(): i32 { return 1.5; }", "expected 'i32', found 'f64'"),
+    ///     ("fn f(a: i8): i32 { return a; }", "expected 'i32', found 'i8'"),
+    /// ];
+    ///
+    /// for (src, message) in mismatches {
+    ///     let errors = ZastPipeline::new().compile(src).errors;
+    ///     assert_eq!(errors.errors()[0].get_error_code(), "E0008");
+    ///     assert!(errors.render_all(src)[0].contains(message));
+    /// }
+    /// ```
+    fn check_expr_type(&mut self, value: &Expression, expected: &ValueType) -> Option<()> {
+        let found = self.infer_expr_type(value)?;
+
+        if let (Some(literal), ValueType::Integer { .. }) =
+            (Self::int_literal_value(value), expected)
+        {
+            if expected.fits_int_literal(literal, self.config.target_pointer_width) {
+                self.record_literal_type(value, expected);
+                return Some(());
+            }
+
+            self.throw_error(ZastError::IntegerOutOfRange {
+                span: value.span,
+                value: literal,
+                ty: expected.clone(),
+            });
+            return None;
+        }
+
+        let float_literal_fits =
+            Self::is_float_literal(value) && matches!(expected, ValueType::Float { .. });
+
+        if float_literal_fits {
+            self.record_literal_type(value, expected);
+            return Some(());
+        }
+
+        if found.is_compatible_with(expected, self.config.allow_int_widening) {
+            return Some(());
+        }
+
+        self.throw_error(ZastError::TypeMismatch {
+            span: value.span,
+            expected: expected.clone(),
+            found,
+        });
+        None
+    }
+
+    /// Resolves the type suffix of an integer literal and checks that `value`
+    /// fits in it, so `200u8` is accepted and `300u8` is not.
+    ///
+    /// ```rust
+    /// use zast::pipeline::ZastPipeline;
+    ///
+    /// let compile = |src: &str| ZastPipeline::new().compile(src);
+    ///
+    /// assert!(compile("fn f(): u8 { return 200u8; }").is_success());
+    /// let errors = compile("fn f(): u8 { return 300u8; }").errors;
+    /// assert_eq!(errors.errors()[0].get_error_code(), "E0021");
+    ///
+    /// // a float suffix pins the literal's type just the same
+    /// assert!(compile("fn g(): f32 { return 3.0f32; }").is_success());
+    /// let src = "fn g(): i32 { return 3.0f32; }";
+    /// assert!(compile(src).errors.render_all(src)[0].contains("found 'f32'"));
+    /// ```
+    fn check_int_suffix(
+        &mut self,
+        value: i64,
+        suffix: &AnnotatedType,
+        span: Span,
+    ) -> Option<ValueType> {
+        let ty = self.resolve_annotated_type(suffix, span)?;
+        if ty.fits_int_literal(value, self.config.target_pointer_width) {
+            return Some(ty);
+        }
+
+        self.throw_error(ZastError::IntegerOutOfRange { span, value, ty });
+        None
+    }
+
+    /// Returns the value of an unsuffixed integer literal, including a negated
+    /// one. Suffixed literals have a fixed type and do not adapt.
+    fn int_literal_value(expr: &Expression) -> Option<i64> {
+        match &expr.node {
+            Expr::IntegerLiteral {
+                value,
+                suffix: None,
+            } => Some(*value),
+            Expr::Negate(operand) => Self::int_literal_value(operand)?.checked_neg(),
+            _ => None,
+        }
+    }
+
+    /// Returns `true` if `as` may convert a `from` value to `to`: between any
+    /// two numeric types, between any two pointer types, or to the same type.
+    fn is_valid_cast(from: &ValueType, to: &ValueType) -> bool {
+        match (from, to) {
+            (
+                ValueType::Integer { .. } | ValueType::Float { .. },
+                ValueType::Integer { .. } | ValueType::Float { .. },
+            ) => true,
+            (ValueType::Pointer(_), ValueType::Pointer(_)) => true,
+            _ => from == to,
+        }
+    }
+
+    fn is_numeric_literal(expr: &Expression) -> bool {
+        Self::int_literal_value(expr).is_some() || Self::is_float_literal(expr)
+    }
+
+    fn is_float_literal(expr: &Expression) -> bool {
+        match &expr.node {
+            Expr::FloatLiteral { suffix: None, .. } => true,
+            Expr::Negate(operand) => Self::is_float_literal(operand),
+            _ => false,
+        }
+    }
+
+    /// Resolves a type annotation, looking up user-defined type names such as
+    /// structs in the type map.
+    ///
+    /// Array lengths are evaluated here, so they may refer to `const` bindings:
+    ///
+    /// ```rust
+    /// use zast::{parser::ZastParser, pipeline::ZastPipeline, sema::ZastSemanticAnalyzer};
+    ///
+    /// let src = "const N: usize = 2; fn f(a: [i32; 2 + 2], b: [i32; N * 3]): void {}";
+    /// let mut analyzer = ZastSemanticAnalyzer::new();
+    /// analyzer.analyze(&ZastParser::from_source(src).unwrap()).unwrap();
+    /// assert_eq!(
+    ///     analyzer.lookup_function("f").unwrap().to_string(),
+    ///     "fn([i32; 4], [i32; 6]): void"
+    /// );
+    ///
+    /// let src = "let x: usize = 2; fn f(a: [i32; x]): void {}";
+    /// let errors = ZastPipeline::new().compile(src).errors;
+    /// assert_eq!(errors.errors()[0].get_error_code(), "E0032");
+    /// ```
+    fn resolve_annotated_type(
+        &mut self,
+        annotated_type: &AnnotatedType,
+        span: Span,
+    ) -> Option<ValueType> {
+        match annotated_type {
+            AnnotatedType::Pointer(pointee) => {
+                let pointee = self.resolve_annotated_type(pointee, span)?;
+                Some(ValueType::Pointer(Box::new(pointee)))
+            }
+            AnnotatedType::Array(element, len) => {
+                let element = self.resolve_annotated_type(element, span)?;
+                let Some(value) = len.eval(&mut |name| self.const_value(name)) else {
+                    self.throw_error(ZastError::NonConstantArrayLength { span });
+                    return None;
+                };
+
+                let Ok(len) = usize::try_from(value) else {
+                    self.throw_error(ZastError::IntegerOutOfRange {
+                        span,
+                        value,
+                        ty: ValueType::Integer {
+                            bits: ValueType::POINTER_SIZED_BITS,
+                            unsigned: true,
+                        },
+                    });
+                    return None;
+                };
+
+                Some(ValueType::Array {
+                    element: Box::new(element),
+                    len,
+                })
+            }
+            AnnotatedType::Function {
+                params,
+                return_type,
+            } => {
+                let params = params
+                    .iter()
+                    .map(|param| self.resolve_annotated_type(param, span))
+                    .collect::<Option<Vec<_>>>()?;
+                let return_type = match return_type.as_ref() {
+                    ReturnType::Void => ValueType::Void,
+                    ReturnType::Type(t) => self.resolve_annotated_type(t, span)?,
+                };
+                Some(ValueType::Function {
+                    params,
+                    return_type: Box::new(return_type),
+                })
+            }
+            AnnotatedType::Primitive(name) => {
+                if let Some(value_type) = self.type_map.resolve_mapping(annotated_type) {
+                    return Some(value_type.clone());
+                }
+
+                self.throw_error(ZastError::UnknownType {
+                    span,
+                    type_name: name.clone(),
+                });
+                None
+            }
+        }
+    }
+
+    /// Returns the folded value of the `const` binding `name` visible here,
+    /// counting the lookup as a read. `None` for anything that isn't an
+    /// integer constant.
+    fn const_value(&mut self, name: &str) -> Option<i64> {
+        let symbol = self.symbol_type_table.resolve_ident_type(name)?;
+        if symbol.is_mutable() {
+            return None;
+        }
+
+        let value = *self.const_values.get(&symbol.span())?;
+        self.symbol_type_table.mark_read(name);
+        Some(value)
+    }
+
+    fn resolve_ident_type(&mut self, identifier: &str, span: Span) -> Option<ValueType> {
+        match self.symbol_type_table.resolve_ident_type(identifier) {
+            Some(symbol_type) => {
+                let value_type = symbol_type.value_type.clone();
+                if self.compound_target != Some(span) {
+                    self.symbol_type_table.mark_read(identifier);
+                }
+                Some(value_type)
+            }
+            None => {
+                self.throw_error(ZastError::UndeclaredVariable {
+                    span,
+                    variable_name: identifier.to_string(),
+                });
+                None
+            }
+        }
+    }
+
+    /// Resolves and analyzes an imported module in its own analyzer, then merges
+    /// its top-level symbols into the global scope. Each path is merged at most
+    /// once, which also breaks import cycles.
+    ///
+    /// A name clashing with one already declared is reported at the import,
+    /// and one declared after the import at its own declaration.
+    ///
+    /// ```rust
+    /// use zast::{parser::ZastParser, sema::ZastSemanticAnalyzer};
+    ///
+    /// fn resolve(_: &str) -> Option<String> {
+    ///     Some(String::from("fn helper(): i32 { return 1; }"))
+    /// }
+    ///
+    /// let src = "const helper = 2;\nimport \"lib.zast\";";
+    /// let mut analyzer = ZastSemanticAnalyzer::new();
+    /// analyzer.set_import_resolver(resolve);
+    /// let errors = analyzer
+    ///     .analyze(&ZastParser::from_source(src).unwrap())
+    ///     .unwrap_err();
+    ///
+    /// assert_eq!(errors.errors()[0].get_error_code(), "E0043");
+    /// let rendered = &errors.render_all(src)[0];
+    /// assert!(rendered.starts_with("Error at: 2:1-17 | 'helper' from import 'lib.zast'"));
+    ///
+    /// let src = "import \"lib.zast\";\nconst helper = 2;";
+    /// let mut analyzer = ZastSemanticAnalyzer::new();
+    /// analyzer.set_import_resolver(resolve);
+    /// let errors = analyzer
+    ///     .analyze(&ZastParser::from_source(src).unwrap())
+    ///     .unwrap_err();
+    ///
+    /// assert!(errors.render_all(src)[0].starts_with("Error at: 2:1-16 | 'helper' from import"));
+    /// ```
+    fn analyze_import(&mut self, path: &str, span: Span) -> Option<()> {
+        if !self.imported_modules.insert(path.to_string()) {
+            return Some(());
+        }
+
+        let Some(source) = self.import_resolver.and_then(|resolve| resolve(path)) else {
+            self.throw_error(ZastError::UnresolvedImport {
+                span,
+                path: path.to_string(),
+            });
+            return None;
+        };
+
+        let program = ZastParser::from_source(&source);
+
+        let Ok(program) = program else {
+            self.throw_error(ZastError::InvalidImport {
+                span,
+                path: path.to_string(),
+            });
+            return None;
+        };
+
+        let mut module = ZastSemanticAnalyzer::with_config(self.config.clone());
+        module.import_resolver = self.import_resolver;
+        module.imported_modules = mem::take(&mut self.imported_modules);
+
+        let analyzed = module.analyze(&program);
+        self.imported_modules = mem::take(&mut module.imported_modules);
+
+        if analyzed.is_err() {
+            self.throw_error(ZastError::InvalidImport {
+                span,
+                path: path.to_string(),
+            });
+            return None;
+        }
+
+        // conflicting spans point into the imported module's source, so the
+        // conflicts are reported at the import instead
+        let conflicts = self
+            .symbol_type_table
+            .merge_globals(&module.symbol_type_table)
+            .err()
+            .unwrap_or_default();
+        let conflicting_names: Vec<String> = conflicts
+            .iter()
+            .filter_map(Self::redeclared_name)
+            .map(str::to_string)
+            .collect();
+
+        for (name, _) in module.symbol_type_table.global_scope().symbols() {
+            if !conflicting_names.contains(name) {
+                self.imported_names.insert(name.clone(), path.to_string());
+            }
+        }
+
+        if conflicting_names.is_empty() {
+            return Some(());
+        }
+
+        for name in conflicting_names {
+            self.throw_error(ZastError::ImportConflict {
+                span,
+                path: path.to_string(),
+                name,
+            });
+        }
+        None
+    }
+
+    fn redeclared_name(error: &ZastError) -> Option<&str> {
+        match error {
+            ZastError::VariableRedeclaration { variable_name, .. } => Some(variable_name),
+            ZastError::FunctionRedeclaration { fn_name, .. } => Some(fn_name),
+            _ => None,
+        }
+    }
+
+    /// Reports a failed declaration. Clashing with an imported top-level
+    /// symbol is reported as [`ZastError::ImportConflict`], since the original
+    /// declaration lives in another source.
+    fn throw_redeclaration(&mut self, zast_error: ZastError) {
+        let import_path = Self::redeclared_name(&zast_error)
+            .filter(|_| self.symbol_type_table.in_global_scope())
+            .and_then(|name| Some((name, self.imported_names.get(name)?)));
+
+        match import_path {
+            Some((name, path)) => self.throw_error(ZastError::ImportConflict {
+                span: zast_error.get_span(),
+                path: path.clone(),
+                name: name.to_string(),
+            }),
+            None => self.throw_error(zast_error),
         }
     }
 
@@ -90,15 +1467,17 @@ impl ZastSemanticAnalyzer {
         &mut self,
         identifier: String,
         value_type: ValueType,
+        mutable: bool,
         span: Span,
     ) -> Option<()> {
+        self.binding_types.insert(span, value_type.clone());
         match self
             .symbol_type_table
-            .declare_ident_type(identifier, value_type, span)
+            .declare_ident_type(identifier, value_type, mutable, span)
         {
             Ok(()) => Some(()),
             Err(zast_err) => {
-                self.throw_error(zast_err);
+                self.throw_redeclaration(*zast_err);
                 None
             }
         }
@@ -111,13 +1490,20 @@ impl ZastSemanticAnalyzer {
         return_type: ValueType,
         span: Span,
     ) -> Option<()> {
+        self.binding_types.insert(
+            span,
+            ValueType::Function {
+                params: params.clone(),
+                return_type: Box::new(return_type.clone()),
+            },
+        );
         match self
             .symbol_type_table
             .declare_function_type(identifier, params, return_type, span)
         {
             Ok(()) => Some(()),
             Err(zast_err) => {
-                self.throw_error(zast_err);
+                self.throw_redeclaration(*zast_err);
                 None
             }
         }
@@ -128,10 +1514,60 @@ impl ZastSemanticAnalyzer {
     }
 
     fn exit_scope(&mut self) {
-        self.symbol_type_table.exit_scope();
+        if let Some(scope) = self.symbol_type_table.exit_scope() {
+            self.report_unread_bindings(&scope);
+        }
+    }
+
+    /// Warns about bindings in `scope` whose value was never read: unused ones,
+    /// and ones that were assigned after their declaration but never read.
+    /// Names starting with `_` are exempt.
+    ///
+    /// ```rust
+    /// use zast::pipeline::ZastPipeline;
+    ///
+    /// let codes = |src: &str| -> Vec<&str> {
+    ///     let errors = ZastPipeline::new().compile(src).errors;
+    ///     errors.errors().iter().map(|error| error.get_error_code()).collect()
+    /// };
+    ///
+    /// assert!(codes("fn f(): i32 { let a = 1; a = a + 1; return a; }").is_empty());
+    /// assert_eq!(codes("fn f(): void { let a = 1; }"), ["E0025"]);
+    /// assert_eq!(codes("fn f(): void { let a = 1; a = 2; }"), ["E0012"]);
+    /// assert_eq!(codes("fn f(): void { let a = 1; a += 1; }"), ["E0012"]);
+    /// assert!(codes("fn f(): i32 { let a = 1; a += 1; return a; }").is_empty());
+    /// assert!(codes("fn f(): void { let _a = 1; _a = 2; }").is_empty());
+    /// ```
+    fn report_unread_bindings(&mut self, scope: &SymbolTypeScope) {
+        let mut unread: Vec<_> = scope
+            .symbols()
+            .filter(|(name, symbol)| symbol.reads == 0 && !name.starts_with('_'))
+            .collect();
+        unread.sort_by_key(|(_, symbol)| (symbol.span.ln_start, symbol.span.col_start));
+
+        for (name, symbol) in unread {
+            let variable_name = name.clone();
+            self.throw_error(if symbol.writes > 0 {
+                ZastError::AssignedButNeverRead {
+                    span: symbol.span,
+                    variable_name,
+                }
+            } else {
+                ZastError::UnusedVariable {
+                    span: symbol.span,
+                    variable_name,
+                }
+            });
+        }
     }
 
     fn throw_error(&mut self, zast_error: ZastError) {
         self.errors.add_error(zast_error);
     }
 }
+
+impl Default for ZastSemanticAnalyzer {
+    fn default() -> Self {
+        Self::new()
+    }
+}
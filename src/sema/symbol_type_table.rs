@@ -2,10 +2,45 @@ use std::collections::HashMap;
 
 use crate::{error_handler::zast_errors::ZastError, lexer::tokens::Span, types::ValueType};
 
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub struct SymbolType {
-    value_type: ValueType,
-    span: Span,
+    pub(crate) value_type: ValueType,
+    pub(crate) span: Span,
+    /// Whether the binding was declared with `let`. `const` bindings,
+    /// function parameters, and functions themselves are immutable.
+    pub(crate) mutable: bool,
+    /// Number of times the binding's value has been read.
+    pub(crate) reads: usize,
+    /// Number of assignments to the binding after its declaration.
+    pub(crate) writes: usize,
+}
+
+impl SymbolType {
+    pub fn value_type(&self) -> &ValueType {
+        &self.value_type
+    }
+
+    pub fn span(&self) -> Span {
+        self.span
+    }
+
+    /// Whether the binding can be assigned to after its declaration.
+    ///
+    /// ```rust
+    /// use zast::pipeline::ZastPipeline;
+    ///
+    /// let result = ZastPipeline::new().compile("fn f(): void { const x = 1; x = 2; }");
+    /// assert_eq!(result.errors.errors()[0].get_error_code(), "E0019");
+    ///
+    /// let src = "fn f(): i32 { let x = 1; x = 2; return x; }";
+    /// assert!(ZastPipeline::new().compile(src).is_success());
+    ///
+    /// let result = ZastPipeline::new().compile("fn g(n: i32): void { n = 2; }");
+    /// assert_eq!(result.errors.errors()[0].get_error_code(), "E0019");
+    /// ```
+    pub fn is_mutable(&self) -> bool {
+        self.mutable
+    }
 }
 
 #[derive(Debug)]
@@ -22,27 +57,30 @@ impl SymbolTypeScope {
         }
     }
 
-    fn declare_function_type(
+    pub fn declare_function_type(
         &mut self,
         identifier: String,
         params: Vec<ValueType>,
         return_type: ValueType,
         span: Span,
-    ) -> Result<(), ZastError> {
+    ) -> Result<(), Box<ZastError>> {
         let symbol_type = SymbolType {
             value_type: ValueType::Function {
                 params,
                 return_type: Box::new(return_type),
             },
             span,
+            mutable: false,
+            reads: 0,
+            writes: 0,
         };
 
         if let Some(original) = self.symbols.insert(identifier.clone(), symbol_type) {
-            return Err(ZastError::FunctionRedeclaration {
+            return Err(Box::new(ZastError::FunctionRedeclaration {
                 span,
                 fn_name: identifier.clone(),
                 original_span: original.span,
-            });
+            }));
         }
 
         self.symbol_count += 1;
@@ -53,16 +91,23 @@ impl SymbolTypeScope {
         &mut self,
         identifier: String,
         value_type: ValueType,
+        mutable: bool,
         span: Span,
-    ) -> Result<(), ZastError> {
-        let symbol_type = SymbolType { value_type, span };
+    ) -> Result<(), Box<ZastError>> {
+        let symbol_type = SymbolType {
+            value_type,
+            span,
+            mutable,
+            reads: 0,
+            writes: 0,
+        };
 
         if let Some(original) = self.symbols.insert(identifier.clone(), symbol_type) {
-            return Err(ZastError::VariableRedeclaration {
+            return Err(Box::new(ZastError::VariableRedeclaration {
                 span: span,
                 variable_name: identifier,
                 original_span: original.span,
-            });
+            }));
         }
 
         self.symbol_count += 1;
@@ -72,6 +117,40 @@ impl SymbolTypeScope {
     pub fn get_ident_type(&mut self, identifier: &str) -> Option<&SymbolType> {
         self.symbols.get(identifier)
     }
+
+    pub fn lookup(&self, identifier: &str) -> Option<&SymbolType> {
+        self.symbols.get(identifier)
+    }
+
+    pub fn symbols(&self) -> impl Iterator<Item = (&String, &SymbolType)> {
+        self.symbols.iter()
+    }
+
+    fn merge_symbol(
+        &mut self,
+        identifier: &str,
+        symbol_type: &SymbolType,
+    ) -> Result<(), Box<ZastError>> {
+        if let Some(original) = self.symbols.get(identifier) {
+            return Err(Box::new(match symbol_type.value_type {
+                ValueType::Function { .. } => ZastError::FunctionRedeclaration {
+                    span: symbol_type.span,
+                    fn_name: identifier.to_string(),
+                    original_span: original.span,
+                },
+                _ => ZastError::VariableRedeclaration {
+                    span: symbol_type.span,
+                    variable_name: identifier.to_string(),
+                    original_span: original.span,
+                },
+            }));
+        }
+
+        self.symbols
+            .insert(identifier.to_string(), symbol_type.clone());
+        self.symbol_count += 1;
+        Ok(())
+    }
 }
 
 #[derive(Debug)]
@@ -92,10 +171,11 @@ impl ZastSymbolTypeTable {
         &mut self,
         identifier: String,
         value_type: ValueType,
+        mutable: bool,
         span: Span,
-    ) -> Result<(), ZastError> {
+    ) -> Result<(), Box<ZastError>> {
         let scope = self.current_scope();
-        scope.declare_ident_type(identifier, value_type, span)
+        scope.declare_ident_type(identifier, value_type, mutable, span)
     }
 
     pub fn declare_function_type(
@@ -104,19 +184,82 @@ impl ZastSymbolTypeTable {
         params: Vec<ValueType>,
         return_type: ValueType,
         span: Span,
-    ) -> Result<(), ZastError> {
+    ) -> Result<(), Box<ZastError>> {
         let scope = self.current_scope();
         scope.declare_function_type(identifier, params, return_type, span)
     }
 
-    pub fn resolve_ident_type(&mut self, identifier: &str) -> Option<&SymbolType> {
-        for scope in self.scopes.iter_mut().rev() {
-            if let Some(t) = scope.get_ident_type(identifier) {
-                return Some(t);
-            }
+    /// Returns the nearest binding named `identifier`, searching from the
+    /// innermost scope outwards. Bindings of scopes that have been exited are
+    /// gone, so a block's locals don't resolve after it ends.
+    ///
+    /// ```rust
+    /// use zast::pipeline::ZastPipeline;
+    ///
+    /// let nested = "fn f(a: i32): i32 { { { return a; } } }";
+    /// assert!(ZastPipeline::new().compile(nested).is_success());
+    ///
+    /// let escaped = "fn f(): i32 { { const b: i32 = 1; } return b; }";
+    /// let errors = ZastPipeline::new().compile(escaped).errors.render_all(escaped);
+    /// assert!(errors.iter().any(|e| e.contains("undeclared variable 'b'")));
+    /// ```
+    pub fn resolve_ident_type(&self, identifier: &str) -> Option<&SymbolType> {
+        self.scopes
+            .iter()
+            .rev()
+            .find_map(|scope| scope.lookup(identifier))
+    }
+
+    /// Records a read of the nearest binding named `identifier`.
+    pub fn mark_read(&mut self, identifier: &str) {
+        if let Some(symbol) = self.resolve_ident_type_mut(identifier) {
+            symbol.reads += 1;
+        }
+    }
+
+    /// Records an assignment to the nearest binding named `identifier`.
+    pub fn mark_write(&mut self, identifier: &str) {
+        if let Some(symbol) = self.resolve_ident_type_mut(identifier) {
+            symbol.writes += 1;
+        }
+    }
+
+    fn resolve_ident_type_mut(&mut self, identifier: &str) -> Option<&mut SymbolType> {
+        self.scopes
+            .iter_mut()
+            .rev()
+            .find_map(|scope| scope.symbols.get_mut(identifier))
+    }
+
+    /// Copies every top-level symbol of `other` into this table's global scope.
+    ///
+    /// Names that are already declared globally keep their existing binding and
+    /// are reported as redeclarations carrying both spans.
+    pub fn merge_globals(&mut self, other: &ZastSymbolTypeTable) -> Result<(), Vec<ZastError>> {
+        let mut incoming: Vec<_> = other.scopes[0].symbols.iter().collect();
+        incoming.sort_by_key(|(_, symbol)| (symbol.span.ln_start, symbol.span.col_start));
+
+        let conflicts: Vec<ZastError> = incoming
+            .into_iter()
+            .filter_map(|(name, symbol)| self.scopes[0].merge_symbol(name, symbol).err())
+            .map(|conflict| *conflict)
+            .collect();
+
+        if conflicts.is_empty() {
+            Ok(())
+        } else {
+            Err(conflicts)
         }
+    }
+
+    /// The outermost scope, holding every top-level declaration.
+    pub fn global_scope(&self) -> &SymbolTypeScope {
+        &self.scopes[0]
+    }
 
-        None
+    /// Whether declarations currently land in the global scope.
+    pub fn in_global_scope(&self) -> bool {
+        self.scope_depth == 0
     }
 
     pub fn enter_scope(&mut self) {
@@ -124,9 +267,15 @@ impl ZastSymbolTypeTable {
         self.scope_depth += 1;
     }
 
-    pub fn exit_scope(&mut self) {
-        self.scopes.pop();
+    /// Pops the innermost scope. The global scope is never popped, so
+    /// top-level declarations stay available once analysis has finished.
+    pub fn exit_scope(&mut self) -> Option<SymbolTypeScope> {
+        if self.scope_depth == 0 {
+            return None;
+        }
+
         self.scope_depth -= 1;
+        self.scopes.pop()
     }
 
     fn current_scope(&mut self) -> &mut SymbolTypeScope {
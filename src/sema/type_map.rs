@@ -1,7 +1,25 @@
 use std::collections::HashMap;
 
-use crate::types::{ValueType, annotated_type::AnnotatedType};
+use crate::types::{FloatWidth, ValueType, annotated_type::AnnotatedType};
 
+/// Maps type names written in annotations to their resolved [`ValueType`].
+///
+/// Built-in primitives are registered up front; user-defined types such as
+/// structs are added as they are declared. Any name missing from the map is
+/// an unknown type.
+///
+/// ```rust
+/// use zast::{sema::type_map::ZastTypeMap, types::{ValueType, annotated_type::AnnotatedType}};
+///
+/// let type_map = ZastTypeMap::new();
+/// let primitive = |name: &str| AnnotatedType::Primitive(String::from(name));
+///
+/// assert_eq!(
+///     type_map.resolve_mapping(&primitive("i32")),
+///     Some(&ValueType::Integer { bits: 32, unsigned: false })
+/// );
+/// assert_eq!(type_map.resolve_mapping(&primitive("i33")), None);
+/// ```
 #[derive(Debug)]
 pub struct ZastTypeMap {
     type_map: HashMap<AnnotatedType, ValueType>,
@@ -9,16 +27,42 @@ pub struct ZastTypeMap {
 
 impl ZastTypeMap {
     pub fn new() -> Self {
-        Self {
+        let mut type_map = Self {
             type_map: HashMap::new(),
+        };
+        type_map.register_primitives();
+        type_map
+    }
+
+    fn register_primitives(&mut self) {
+        for bits in [8, 16, 32, 64, 128, ValueType::POINTER_SIZED_BITS] {
+            for unsigned in [false, true] {
+                let value_type = ValueType::Integer { bits, unsigned };
+                self.add_mapping(AnnotatedType::Primitive(value_type.to_string()), value_type);
+            }
         }
+
+        for width in [
+            FloatWidth::F16,
+            FloatWidth::F32,
+            FloatWidth::F64,
+            FloatWidth::F128,
+        ] {
+            let value_type = ValueType::Float { width };
+            self.add_mapping(AnnotatedType::Primitive(value_type.to_string()), value_type);
+        }
+
+        self.add_mapping(
+            AnnotatedType::Primitive(String::from("bool")),
+            ValueType::Bool,
+        );
     }
 
     pub fn add_mapping(&mut self, annotated_type: AnnotatedType, value_type: ValueType) {
         self.type_map.insert(annotated_type, value_type);
     }
 
-    pub fn resolve_mapping(&mut self, annotated_type: AnnotatedType) -> Option<&ValueType> {
-        self.type_map.get(&annotated_type)
+    pub fn resolve_mapping(&self, annotated_type: &AnnotatedType) -> Option<&ValueType> {
+        self.type_map.get(annotated_type)
     }
 }
@@ -0,0 +1,89 @@
+use std::collections::HashMap;
+
+use crate::{
+    ast::{Expression, NodeId, ZastProgram},
+    lexer::tokens::Span,
+    types::ValueType,
+};
+
+/// A program that passed semantic analysis, together with the type the
+/// analyzer settled on for each of its expressions and declarations.
+///
+/// Numeric literals carry the type they adapted to, so the `5` in
+/// `let x: u8 = 5;` is recorded as `u8` rather than the default integer type.
+#[derive(Debug)]
+pub struct TypedProgram {
+    pub program: ZastProgram,
+    expr_types: HashMap<NodeId, ValueType>,
+    // keyed by the span of the declaring statement or parameter
+    binding_types: HashMap<Span, ValueType>,
+}
+
+impl TypedProgram {
+    pub fn new(
+        program: ZastProgram,
+        expr_types: HashMap<NodeId, ValueType>,
+        binding_types: HashMap<Span, ValueType>,
+    ) -> Self {
+        Self {
+            program,
+            expr_types,
+            binding_types,
+        }
+    }
+
+    /// Returns the inferred type of `expr`, or `None` if the analyzer never
+    /// visited it.
+    ///
+    /// Types are recorded per node, so the assignment and the addition that
+    /// `n += 300` desugars to each keep their own type despite sharing a span.
+    ///
+    /// ```rust
+    /// use zast::{
+    ///     ast::{Expr, Stmt},
+    ///     parser::ZastParser,
+    ///     sema::ZastSemanticAnalyzer,
+    ///     types::ValueType,
+    /// };
+    ///
+    /// let src = "let n: u16 = 1;\nconst small = n < 2;\nn += 300;";
+    /// let program = ZastParser::from_source(src).unwrap();
+    /// let typed = ZastSemanticAnalyzer::new().analyze_typed(program).unwrap();
+    /// let u16 = ValueType::Integer { bits: 16, unsigned: true };
+    ///
+    /// let Stmt::VariableDeclaration { value: comparison, .. } = &typed.program.body[1].node else {
+    ///     unreachable!()
+    /// };
+    /// let Expr::BinaryExpression { left, .. } = &comparison.node else { unreachable!() };
+    /// assert_eq!(typed.type_of(comparison), Some(&ValueType::Bool));
+    /// assert_eq!(typed.type_of(left), Some(&u16));
+    ///
+    /// let Stmt::Expression { expression: assignment } = &typed.program.body[2].node else {
+    ///     unreachable!()
+    /// };
+    /// let Expr::Assignment { value: sum, .. } = &assignment.node else { unreachable!() };
+    /// let Expr::BinaryExpression { right, .. } = &sum.node else { unreachable!() };
+    /// assert_eq!(sum.span, assignment.span);
+    /// assert_ne!(sum.id, assignment.id);
+    /// assert_eq!(typed.type_of(sum), Some(&u16));
+    /// assert_eq!(typed.type_of(right), Some(&u16));
+    /// ```
+    pub fn type_of(&self, expr: &Expression) -> Option<&ValueType> {
+        self.expr_types.get(&expr.id)
+    }
+
+    /// Returns the resolved type of the binding declared at `span`: a
+    /// variable declaration, a function parameter, or a function, whose type
+    /// is a [`ValueType::Function`].
+    pub fn binding_type(&self, span: Span) -> Option<&ValueType> {
+        self.binding_types.get(&span)
+    }
+
+    pub fn expr_types(&self) -> &HashMap<NodeId, ValueType> {
+        &self.expr_types
+    }
+
+    pub fn binding_types(&self) -> &HashMap<Span, ValueType> {
+        &self.binding_types
+    }
+}
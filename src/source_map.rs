@@ -0,0 +1,112 @@
+use crate::lexer::tokens::Span;
+
+/// Maps [`Span`]s back to the source text they were lexed from, for snippet
+/// rendering and editor integrations.
+///
+/// Lines and columns are 1-based, as in [`Span`]. A tab covers
+/// [`tab_width`](SourceMap::with_tab_width) columns, matching
+/// [`ZastConfig::tab_width`](crate::config::ZastConfig::tab_width), so spans
+/// lexed with a wider tab map back to the right characters. A `\r\n` line
+/// break counts as a single `\n`.
+///
+/// # Example
+///
+/// ```rust
+/// use zast::{lexer::tokens::Span, source_map::SourceMap};
+///
+/// let map = SourceMap::new("ab\ncd");
+/// let span = Span { ln_start: 2, ln_end: 2, col_start: 1, col_end: 2 };
+///
+/// assert_eq!(map.span_text(span), Some("cd"));
+/// assert_eq!(map.line(2), Some("cd"));
+/// assert_eq!(map.offset(2, 2), Some(4));
+/// ```
+#[derive(Debug, Clone)]
+pub struct SourceMap<'a> {
+    src: &'a str,
+    /// Byte offset at which each line starts.
+    line_starts: Vec<usize>,
+    tab_width: usize,
+}
+
+impl<'a> SourceMap<'a> {
+    pub fn new(src: &'a str) -> Self {
+        Self::with_tab_width(src, 1)
+    }
+
+    pub fn with_tab_width(src: &'a str, tab_width: usize) -> Self {
+        let line_starts = std::iter::once(0)
+            .chain(src.match_indices('\n').map(|(pos, _)| pos + 1))
+            .collect();
+
+        Self {
+            src,
+            line_starts,
+            tab_width,
+        }
+    }
+
+    /// Returns the number of lines in the source. A trailing newline starts
+    /// a final, empty line.
+    pub fn line_count(&self) -> usize {
+        self.line_starts.len()
+    }
+
+    /// Returns the text of line `ln` without its line terminator.
+    pub fn line(&self, ln: usize) -> Option<&'a str> {
+        let line = self.line_with_terminator(ln)?;
+        let line = line.strip_suffix('\n').unwrap_or(line);
+        Some(line.strip_suffix('\r').unwrap_or(line))
+    }
+
+    /// Returns the full lines `span` starts and ends on, from the start of
+    /// its first line to the end of its last, without the final terminator.
+    pub fn lines(&self, span: Span) -> Option<&'a str> {
+        let start = *self.line_starts.get(span.ln_start.checked_sub(1)?)?;
+        let last = self.line(span.ln_end)?;
+        let end = self.line_starts[span.ln_end - 1] + last.len();
+
+        self.src.get(start..end)
+    }
+
+    /// Returns the byte offset of the character covering column `col` of
+    /// line `ln`. The column just past a line's last character, where its
+    /// terminator or the end of input sits, is also valid.
+    pub fn offset(&self, ln: usize, col: usize) -> Option<usize> {
+        let line_start = *self.line_starts.get(ln.checked_sub(1)?)?;
+        let line = self.line_with_terminator(ln)?;
+        let mut column = 1;
+
+        for (pos, c) in line.char_indices() {
+            // as in the lexer, the `\r` of a `\r\n` takes no column of its own
+            let width = match c {
+                '\t' => self.tab_width,
+                '\r' if line[pos..].starts_with("\r\n") => 0,
+                _ => 1,
+            };
+            if col < column + width {
+                return (col >= column).then_some(line_start + pos);
+            }
+            column += width;
+        }
+
+        (col == column).then_some(line_start + line.len())
+    }
+
+    /// Returns the source text `span` covers, from its first character
+    /// through its last, inclusive.
+    pub fn span_text(&self, span: Span) -> Option<&'a str> {
+        let start = self.offset(span.ln_start, span.col_start)?;
+        let last = self.offset(span.ln_end, span.col_end)?;
+        let end = last + self.src[last..].chars().next().map_or(0, char::len_utf8);
+
+        self.src.get(start..end)
+    }
+
+    fn line_with_terminator(&self, ln: usize) -> Option<&'a str> {
+        let start = *self.line_starts.get(ln.checked_sub(1)?)?;
+        let end = self.line_starts.get(ln).copied().unwrap_or(self.src.len());
+
+        self.src.get(start..end)
+    }
+}
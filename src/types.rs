@@ -1,9 +1,12 @@
+use core::fmt;
+
 use crate::types::{annotated_type::AnnotatedType, return_type::ReturnType};
 
 pub mod annotated_type;
+pub mod const_expr;
 pub mod return_type;
 
-#[derive(Debug, Hash, PartialEq, Eq)]
+#[derive(Debug, Hash, PartialEq, Eq, Clone)]
 pub enum FloatWidth {
     F16,
     F32,
@@ -11,7 +14,7 @@ pub enum FloatWidth {
     F128,
 }
 
-#[derive(Debug, Hash, PartialEq, Eq)]
+#[derive(Debug, Hash, PartialEq, Eq, Clone)]
 pub enum ValueType {
     Integer {
         bits: u16,
@@ -21,7 +24,19 @@ pub enum ValueType {
         width: FloatWidth,
     },
     Pointer(Box<ValueType>),
+    Array {
+        element: Box<ValueType>,
+        len: usize,
+    },
     Bool,
+    Struct {
+        name: String,
+        fields: Vec<(String, ValueType)>,
+    },
+    Enum {
+        name: String,
+        variants: Vec<String>,
+    },
 
     Void, // return type
     Function {
@@ -30,7 +45,226 @@ pub enum ValueType {
     },
 }
 
+impl fmt::Display for FloatWidth {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::F16 => write!(f, "f16"),
+            Self::F32 => write!(f, "f32"),
+            Self::F64 => write!(f, "f64"),
+            Self::F128 => write!(f, "f128"),
+        }
+    }
+}
+
+/// Renders the type in Zast surface syntax, e.g. `*u8`, `[i32; 4]`,
+/// `fn(i32, i32): void`.
+///
+/// ```rust
+/// use zast::types::ValueType;
+///
+/// let i32_type = ValueType::Integer { bits: 32, unsigned: false };
+/// let u8_type = ValueType::Integer { bits: 8, unsigned: true };
+/// let callback = ValueType::Function {
+///     params: vec![i32_type.clone(), i32_type.clone()],
+///     return_type: Box::new(ValueType::Void),
+/// };
+///
+/// assert_eq!(i32_type.to_string(), "i32");
+/// assert_eq!(ValueType::Pointer(Box::new(u8_type)).to_string(), "*u8");
+/// assert_eq!(ValueType::Bool.to_string(), "bool");
+/// assert_eq!(callback.to_string(), "fn(i32, i32): void");
+/// ```
+impl fmt::Display for ValueType {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Integer { bits, unsigned } => {
+                let prefix = if *unsigned { "u" } else { "i" };
+                if *bits == Self::POINTER_SIZED_BITS {
+                    write!(f, "{}size", prefix)
+                } else {
+                    write!(f, "{}{}", prefix, bits)
+                }
+            }
+            Self::Float { width } => write!(f, "{}", width),
+            Self::Pointer(pointee) => write!(f, "*{}", pointee),
+            Self::Array { element, len } => write!(f, "[{}; {}]", element, len),
+            Self::Bool => write!(f, "bool"),
+            Self::Struct { name, .. } | Self::Enum { name, .. } => write!(f, "{}", name),
+            Self::Void => write!(f, "void"),
+            Self::Function {
+                params,
+                return_type,
+            } => {
+                let params: Vec<String> = params.iter().map(|p| p.to_string()).collect();
+                write!(f, "fn({}): {}", params.join(", "), return_type)
+            }
+        }
+    }
+}
+
 impl ValueType {
+    /// Sentinel `bits` value for `isize`/`usize`, whose width depends on
+    /// [`ZastConfig::target_pointer_width`](crate::config::ZastConfig::target_pointer_width).
+    pub const POINTER_SIZED_BITS: u16 = 0;
+
+    /// Returns the width of an integer type in bits, resolving pointer-sized
+    /// integers against `pointer_width`. Returns `None` for non-integers.
+    ///
+    /// ```rust
+    /// use zast::{pipeline::ZastPipeline, types::ValueType};
+    ///
+    /// let src = "fn f(): usize { let n: usize = 0; const p: *usize = &n; return *p + 1; }";
+    /// assert!(ZastPipeline::new().compile(src).is_success());
+    ///
+    /// let usize_type = ValueType::Integer { bits: ValueType::POINTER_SIZED_BITS, unsigned: true };
+    /// assert_eq!(usize_type.to_string(), "usize");
+    /// assert_eq!(usize_type.int_bit_width(32), Some(32));
+    /// ```
+    pub fn int_bit_width(&self, pointer_width: u16) -> Option<u16> {
+        match self {
+            Self::Integer { bits, .. } if *bits == Self::POINTER_SIZED_BITS => Some(pointer_width),
+            Self::Integer { bits, .. } => Some(*bits),
+            _ => None,
+        }
+    }
+
+    /// Returns the smallest and largest value of an integer type, resolving
+    /// pointer-sized integers against `pointer_width`. Returns `None` for
+    /// non-integers.
+    ///
+    /// The upper bound of `u128` saturates at `i128::MAX`, which is still far
+    /// beyond any literal.
+    ///
+    /// ```rust
+    /// use zast::{pipeline::ZastPipeline, types::ValueType};
+    ///
+    /// let i128_type = ValueType::Integer { bits: 128, unsigned: false };
+    /// let u128_type = ValueType::Integer { bits: 128, unsigned: true };
+    /// assert_eq!(i128_type.int_range(64), Some((i128::MIN, i128::MAX)));
+    /// assert_eq!(u128_type.int_range(64), Some((0, i128::MAX)));
+    ///
+    /// for src in ["const x: i128 = 1;", "fn f(): u128 { return 5; }", "const x = 5u128;"] {
+    ///     assert!(ZastPipeline::new().compile(src).is_success());
+    /// }
+    /// let errors = ZastPipeline::new().compile("const x: u128 = -1;").errors;
+    /// assert_eq!(errors.errors()[0].get_error_code(), "E0021");
+    /// ```
+    pub fn int_range(&self, pointer_width: u16) -> Option<(i128, i128)> {
+        let (Some(bits), Self::Integer { unsigned, .. }) =
+            (self.int_bit_width(pointer_width), self)
+        else {
+            return None;
+        };
+
+        let value_bits = if *unsigned { bits } else { bits - 1 };
+        // 2^127 - 1 is already i128::MAX, and 2^128 - 1 doesn't fit
+        let max = if value_bits >= 127 {
+            i128::MAX
+        } else {
+            (1i128 << value_bits) - 1
+        };
+
+        if *unsigned {
+            Some((0, max))
+        } else {
+            Some((-max - 1, max))
+        }
+    }
+
+    /// Returns `true` if the integer literal `value` is representable in this
+    /// integer type. Always `false` for non-integer types.
+    ///
+    /// ```rust
+    /// use zast::pipeline::ZastPipeline;
+    ///
+    /// assert!(ZastPipeline::new().compile("const x: u8 = 200;").is_success());
+    ///
+    /// for src in ["const x: i8 = 200;", "const x: u8 = -1;"] {
+    ///     let errors = ZastPipeline::new().compile(src).errors;
+    ///     assert_eq!(errors.errors()[0].get_error_code(), "E0021");
+    /// }
+    /// ```
+    pub fn fits_int_literal(&self, value: i64, pointer_width: u16) -> bool {
+        self.int_range(pointer_width)
+            .is_some_and(|(min, max)| (min..=max).contains(&i128::from(value)))
+    }
+
+    /// Returns `true` if a value of this type can initialize a binding of
+    /// type `other`.
+    ///
+    /// This is exact type equality for now; implicit pointer coercions would
+    /// be added here.
+    ///
+    /// ```rust
+    /// use zast::types::ValueType;
+    ///
+    /// let i32_type = ValueType::Integer { bits: 32, unsigned: false };
+    /// let i64_type = ValueType::Integer { bits: 64, unsigned: false };
+    ///
+    /// assert!(i32_type.is_assignable_to(&i32_type.clone()));
+    /// assert!(!i32_type.is_assignable_to(&i64_type));
+    /// assert!(!ValueType::Bool.is_assignable_to(&i32_type));
+    /// ```
+    pub fn is_assignable_to(&self, other: &ValueType) -> bool {
+        self == other
+    }
+
+    /// Returns `true` if a value of this type can be used where `target` is
+    /// expected.
+    ///
+    /// Types must match exactly, except that with `allow_int_widening` an
+    /// integer may widen to a larger integer of the same signedness
+    /// (`i8` → `i32`, `u16` → `u64`). Narrowing and sign changes are never
+    /// implicit, and pointer-sized integers only match themselves since their
+    /// width is target-dependent.
+    ///
+    /// ```rust
+    /// use zast::{config::ZastConfig, pipeline::ZastPipeline};
+    ///
+    /// let src = "fn f(some_i8: i8): i32 { let x: i32 = some_i8; return x; }";
+    /// let widening = ZastConfig::new().allow_int_widening(true);
+    ///
+    /// assert!(ZastPipeline::with_config(widening).compile(src).is_success());
+    /// let errors = ZastPipeline::new().compile(src).errors;
+    /// assert_eq!(errors.errors()[0].get_error_code(), "E0008");
+    /// ```
+    ///
+    /// Pointers are compared structurally, pointee and depth included, and
+    /// widening never applies through them: `*i32` accepts `&x` for `x: i32`
+    /// but rejects `&flag` (`*bool`), `&p` (`**i32`), and `&small` (`*i8`).
+    ///
+    /// ```rust
+    /// use zast::pipeline::ZastPipeline;
+    ///
+    /// let src = "fn f(x: i32): i32 { let p: *i32 = &x; return *p; }";
+    /// assert!(ZastPipeline::new().compile(src).is_success());
+    ///
+    /// let src = "fn f(b: bool): void { let p: *i32 = &b; }";
+    /// let errors = ZastPipeline::new().compile(src).errors;
+    /// assert_eq!(errors.errors()[0].get_error_code(), "E0008");
+    /// assert!(errors.render_all(src)[0].contains("expected '*i32', found '*bool'"));
+    /// ```
+    pub fn is_compatible_with(&self, target: &ValueType, allow_int_widening: bool) -> bool {
+        match (self, target) {
+            (
+                Self::Integer {
+                    bits: from_bits,
+                    unsigned: from_unsigned,
+                },
+                Self::Integer {
+                    bits: to_bits,
+                    unsigned: to_unsigned,
+                },
+            ) if allow_int_widening
+                && *from_bits != Self::POINTER_SIZED_BITS
+                && *to_bits != Self::POINTER_SIZED_BITS =>
+            {
+                from_unsigned == to_unsigned && from_bits <= to_bits
+            }
+            _ => self.is_assignable_to(target),
+        }
+    }
+
     pub fn from_return_type(return_type: ReturnType) -> Self {
         match return_type {
             ReturnType::Void => Self::Void,
@@ -38,37 +272,69 @@ impl ValueType {
         }
     }
 
+    /// Converts a built-in type annotation.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the annotation names a type that isn't built in, such as a
+    /// struct. Use [`ValueType::try_from_annotated_type`] when that can happen.
     pub fn from_annotated_type(annotated_type: AnnotatedType) -> Self {
+        Self::try_from_annotated_type(&annotated_type).unwrap()
+    }
+
+    /// Converts a built-in type annotation without consulting sema's type
+    /// map. Returns `None` if it names a type that isn't built in, such as a
+    /// struct, anywhere inside it.
+    pub fn try_from_annotated_type(annotated_type: &AnnotatedType) -> Option<Self> {
         match annotated_type {
             AnnotatedType::Pointer(a) => {
-                let ptr = Self::from_annotated_type(*a);
-                Self::Pointer(Box::new(ptr))
+                let ptr = Self::try_from_annotated_type(a)?;
+                Some(Self::Pointer(Box::new(ptr)))
             }
 
+            AnnotatedType::Array(element, len) => Some(Self::Array {
+                element: Box::new(Self::try_from_annotated_type(element)?),
+                len: usize::try_from(len.eval(&mut |_| None)?).ok()?,
+            }),
+
+            AnnotatedType::Function {
+                params,
+                return_type,
+            } => Some(Self::Function {
+                params: params
+                    .iter()
+                    .map(Self::try_from_annotated_type)
+                    .collect::<Option<_>>()?,
+                return_type: Box::new(match return_type.as_ref() {
+                    ReturnType::Void => Self::Void,
+                    ReturnType::Type(t) => Self::try_from_annotated_type(t)?,
+                }),
+            }),
+
             AnnotatedType::Primitive(_) => {
                 if annotated_type.is_int() {
-                    let width = annotated_type.get_int_bitwidth().unwrap();
-                    return Self::Integer {
+                    let width = annotated_type.get_int_bitwidth()?;
+                    return Some(Self::Integer {
                         bits: width,
                         unsigned: false,
-                    };
+                    });
                 }
                 if annotated_type.is_unsigned() {
-                    let width = annotated_type.get_unsigned_bitwidth().unwrap();
-                    return Self::Integer {
+                    let width = annotated_type.get_unsigned_bitwidth()?;
+                    return Some(Self::Integer {
                         bits: width,
                         unsigned: true,
-                    };
+                    });
                 }
                 if annotated_type.is_float() {
-                    let width = annotated_type.get_float_bitwidth().unwrap();
-                    return Self::Float { width };
+                    let width = annotated_type.get_float_bitwidth()?;
+                    return Some(Self::Float { width });
                 }
                 if annotated_type.is_bool() {
-                    return Self::Bool;
+                    return Some(Self::Bool);
                 }
 
-                unreachable!()
+                None
             }
         }
     }
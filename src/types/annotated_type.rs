@@ -1,16 +1,57 @@
-use crate::types::FloatWidth;
+use core::fmt;
 
+use crate::types::{FloatWidth, ValueType, const_expr::ConstExpr, return_type::ReturnType};
+
+/// A type as written in a source annotation, before semantic analysis
+/// resolves it to a [`ValueType`]. Annotations compare structurally, so they
+/// can key a map of resolved types.
+///
+/// ```rust
+/// use std::collections::HashMap;
+/// use zast::types::{ValueType, annotated_type::AnnotatedType};
+///
+/// let ptr = AnnotatedType::Pointer(Box::new(AnnotatedType::Primitive(String::from("u8"))));
+/// let mut resolved = HashMap::new();
+/// resolved.insert(ptr.clone(), ValueType::from_annotated_type(ptr.clone()));
+///
+/// assert_eq!(resolved[&ptr].to_string(), "*u8");
+/// ```
 #[derive(Debug, Hash, PartialEq, Eq, Clone)]
 pub enum AnnotatedType {
     Primitive(String),
     Pointer(Box<AnnotatedType>),
+    Array(Box<AnnotatedType>, ConstExpr),
+    Function {
+        params: Vec<AnnotatedType>,
+        return_type: Box<ReturnType>,
+    },
+}
+
+/// Renders the annotation as it is written in Zast source, e.g. `*u8`,
+/// `[i32; N * 2]`, or `fn(i32): void`.
+impl fmt::Display for AnnotatedType {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Primitive(name) => write!(f, "{}", name),
+            Self::Pointer(pointee) => write!(f, "*{}", pointee),
+            Self::Array(element, len) => write!(f, "[{}; {}]", element, len),
+            Self::Function {
+                params,
+                return_type,
+            } => {
+                let params: Vec<String> = params.iter().map(|p| p.to_string()).collect();
+                write!(f, "fn({}): {}", params.join(", "), return_type)
+            }
+        }
+    }
 }
 
 impl AnnotatedType {
     pub fn is_int(&self) -> bool {
         match self {
             Self::Primitive(t) => {
-                t.starts_with("i") && t[1..].parse::<u16>().map(|n| n >= 1).unwrap_or(false)
+                t == "isize"
+                    || t.starts_with("i") && t[1..].parse::<u16>().map(|n| n >= 1).unwrap_or(false)
             }
             _ => false,
         }
@@ -19,7 +60,8 @@ impl AnnotatedType {
     pub fn is_unsigned(&self) -> bool {
         match self {
             Self::Primitive(t) => {
-                t.starts_with("u") && t[1..].parse::<u16>().map(|n| n >= 1).unwrap_or(false)
+                t == "usize"
+                    || t.starts_with("u") && t[1..].parse::<u16>().map(|n| n >= 1).unwrap_or(false)
             }
             _ => false,
         }
@@ -68,6 +110,9 @@ impl AnnotatedType {
     pub fn get_int_bitwidth(&self) -> Option<u16> {
         match self {
             Self::Primitive(t) => {
+                if t == "isize" {
+                    return Some(ValueType::POINTER_SIZED_BITS);
+                }
                 if !t.starts_with("i") {
                     return None;
                 }
@@ -84,6 +129,9 @@ impl AnnotatedType {
     pub fn get_unsigned_bitwidth(&self) -> Option<u16> {
         match self {
             Self::Primitive(t) => {
+                if t == "usize" {
+                    return Some(ValueType::POINTER_SIZED_BITS);
+                }
                 if !t.starts_with("u") {
                     return None;
                 }
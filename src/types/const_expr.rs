@@ -0,0 +1,119 @@
+use core::fmt;
+
+use crate::{
+    ast::{Expr, Expression},
+    lexer::tokens::TokenKind,
+};
+
+/// An integer expression that can be evaluated at compile time, as written
+/// in an array length, e.g. the `N * 2` in `[i32; N * 2]`.
+#[derive(Debug, Hash, PartialEq, Eq, Clone)]
+pub enum ConstExpr {
+    Int(i64),
+    // resolved against `const` bindings during evaluation
+    Name(String),
+    Negate(Box<ConstExpr>),
+    Binary(ConstOp, Box<ConstExpr>, Box<ConstExpr>),
+}
+
+#[derive(Debug, Hash, PartialEq, Eq, Clone, Copy)]
+pub enum ConstOp {
+    Add,
+    Sub,
+    Mul,
+}
+
+impl ConstExpr {
+    /// Converts `expr` if it only uses integer literals, names, negation,
+    /// `+`, `-` and `*`. Returns `None` for anything else, such as calls.
+    pub fn from_expr(expr: &Expression) -> Option<Self> {
+        match &expr.node {
+            Expr::IntegerLiteral { value, .. } => Some(Self::Int(*value)),
+            Expr::Identifier(name) => Some(Self::Name(name.clone())),
+            Expr::Negate(operand) => Some(Self::Negate(Box::new(Self::from_expr(operand)?))),
+            Expr::BinaryExpression {
+                left,
+                operator,
+                right,
+            } => {
+                let op = match operator {
+                    TokenKind::Plus => ConstOp::Add,
+                    TokenKind::Minus => ConstOp::Sub,
+                    TokenKind::Multiply => ConstOp::Mul,
+                    _ => return None,
+                };
+                Some(Self::Binary(
+                    op,
+                    Box::new(Self::from_expr(left)?),
+                    Box::new(Self::from_expr(right)?),
+                ))
+            }
+            _ => None,
+        }
+    }
+
+    /// Evaluates the expression, looking names up with `resolve_name`.
+    /// Returns `None` if a name isn't a known constant or the arithmetic
+    /// overflows `i64`.
+    pub fn eval(&self, resolve_name: &mut impl FnMut(&str) -> Option<i64>) -> Option<i64> {
+        match self {
+            Self::Int(value) => Some(*value),
+            Self::Name(name) => resolve_name(name),
+            Self::Negate(operand) => operand.eval(resolve_name)?.checked_neg(),
+            Self::Binary(op, left, right) => {
+                let (left, right) = (left.eval(resolve_name)?, right.eval(resolve_name)?);
+                match op {
+                    ConstOp::Add => left.checked_add(right),
+                    ConstOp::Sub => left.checked_sub(right),
+                    ConstOp::Mul => left.checked_mul(right),
+                }
+            }
+        }
+    }
+}
+
+impl ConstOp {
+    // binds tighter than any lower value
+    fn precedence(self) -> u8 {
+        match self {
+            Self::Add | Self::Sub => 0,
+            Self::Mul => 1,
+        }
+    }
+}
+
+/// Renders the expression as Zast source, e.g. `-(N + 1) * 2`, adding
+/// parentheses only where precedence requires them.
+impl fmt::Display for ConstExpr {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Int(value) => write!(f, "{}", value),
+            Self::Name(name) => write!(f, "{}", name),
+            Self::Negate(operand) => match operand.as_ref() {
+                Self::Binary(..) | Self::Negate(_) => write!(f, "-({})", operand),
+                _ => write!(f, "-{}", operand),
+            },
+            Self::Binary(op, left, right) => {
+                let symbol = match op {
+                    ConstOp::Add => "+",
+                    ConstOp::Sub => "-",
+                    ConstOp::Mul => "*",
+                };
+
+                match left.as_ref() {
+                    Self::Binary(left_op, ..) if left_op.precedence() < op.precedence() => {
+                        write!(f, "({})", left)?
+                    }
+                    _ => write!(f, "{}", left)?,
+                }
+                write!(f, " {} ", symbol)?;
+                match right.as_ref() {
+                    Self::Binary(right_op, ..) if right_op.precedence() <= op.precedence() => {
+                        write!(f, "({})", right)
+                    }
+                    _ => write!(f, "{}", right),
+                }
+            }
+        }
+    }
+}
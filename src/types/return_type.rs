@@ -1,7 +1,18 @@
+use core::fmt;
+
 use crate::types::annotated_type::AnnotatedType;
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub enum ReturnType {
     Void,
     Type(AnnotatedType),
 }
+
+impl fmt::Display for ReturnType {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Void => write!(f, "void"),
+            Self::Type(annotated_type) => write!(f, "{}", annotated_type),
+        }
+    }
+}
@@ -1,62 +1,847 @@
+use std::{collections::HashMap, mem};
+
 use crate::{
-    ast::{Stmt, ZastProgram},
-    types::{ValueType, return_type::ReturnType},
-    zast_ir::ir_instructions::{ZastIRInstruction, ZastIRProgram},
+    ast::{Argument, Expr, Expression, NodeId, Statement, Stmt, ZastProgram},
+    config::ZastConfig,
+    error_handler::{ZastErrorCollector, zast_errors::ZastError},
+    lexer::tokens::{Span, TokenKind},
+    sema::typed_program::TypedProgram,
+    types::{FloatWidth, ValueType, annotated_type::AnnotatedType, return_type::ReturnType},
+    zast_ir::{
+        ir_instructions::{BinaryOp, UnaryOp, ZastIRInstruction, ZastIRProgram},
+        ir_values::ZastIRValue,
+    },
 };
 
 pub mod ir_instructions;
+pub mod ir_passes;
 pub mod ir_values;
 
-pub struct ZastIREmitter;
+pub struct ZastIREmitter {
+    config: ZastConfig,
+    errors: ZastErrorCollector,
+    next_temp: usize,
+    local_types: HashMap<String, ValueType>,
+    temp_types: HashMap<usize, ValueType>,
+    function_return_types: HashMap<String, ValueType>,
+    function_params: HashMap<String, Vec<String>>,
+    enum_variants: HashMap<String, Vec<String>>,
+    next_label: usize,
+    // types inferred by sema, empty unless emitting a TypedProgram
+    expr_types: HashMap<NodeId, ValueType>,
+    binding_types: HashMap<Span, ValueType>,
+}
 
 impl ZastIREmitter {
     pub fn new() -> Self {
-        Self
+        Self::with_config(ZastConfig::default())
+    }
+
+    pub fn with_config(config: ZastConfig) -> Self {
+        Self {
+            errors: ZastErrorCollector::from_config(&config),
+            config,
+            next_temp: 0,
+            local_types: HashMap::new(),
+            temp_types: HashMap::new(),
+            function_return_types: HashMap::new(),
+            function_params: HashMap::new(),
+            enum_variants: HashMap::new(),
+            next_label: 0,
+            expr_types: HashMap::new(),
+            binding_types: HashMap::new(),
+        }
+    }
+
+    pub fn config(&self) -> &ZastConfig {
+        &self.config
     }
 
-    pub fn emit(&self, program: &ZastProgram) -> ZastIRProgram {
+    /// Emits IR for an analyzed program, taking the types of temporaries,
+    /// locals, parameters and functions from the analyzer instead of
+    /// re-deriving them.
+    ///
+    /// ```rust
+    /// use zast::{parser::ZastParser, sema::ZastSemanticAnalyzer, zast_ir::ZastIREmitter};
+    ///
+    /// let src = "struct Point { x: i32, y: i32 } fn f(p: Point): void {}";
+    /// let program = ZastParser::from_source(src).unwrap();
+    /// let typed = ZastSemanticAnalyzer::new().analyze_typed(program).unwrap();
+    ///
+    /// let ir = ZastIREmitter::new().emit_typed(&typed).unwrap();
+    /// assert!(ir.to_string().starts_with("fn f(p: Point): void {"));
+    ///
+    /// // a type sema rejected is emitted as `void` rather than panicking
+    /// let program = ZastParser::from_source("fn g(p: Missing): void {}").unwrap();
+    /// assert!(ZastSemanticAnalyzer::new().analyze(&program).is_err());
+    /// let ir = ZastIREmitter::new().emit(&program).unwrap();
+    /// assert!(ir.to_string().starts_with("fn g(p: void): void {"));
+    /// ```
+    pub fn emit_typed(
+        &mut self,
+        typed: &TypedProgram,
+    ) -> Result<ZastIRProgram, ZastErrorCollector> {
+        self.expr_types = typed.expr_types().clone();
+        self.binding_types = typed.binding_types().clone();
+        let program = self.emit(&typed.program);
+        self.expr_types.clear();
+        self.binding_types.clear();
+        program
+    }
+
+    /// Emits IR for `program` without sema's types, converting built-in type
+    /// annotations directly. Types it cannot resolve on its own, such as
+    /// structs, are emitted as `void`; prefer [`ZastIREmitter::emit_typed`].
+    ///
+    /// Negated numeric literals are emitted as a single constant:
+    ///
+    /// ```rust
+    /// use zast::{parser::ZastParser, zast_ir::ZastIREmitter};
+    ///
+    /// let program = ZastParser::from_source("fn f(): i32 { -5 }").unwrap();
+    /// let ir = ZastIREmitter::new().emit(&program).unwrap().to_string();
+    ///
+    /// assert!(ir.contains("ret -5"));
+    /// assert!(!ir.contains("neg"));
+    /// ```
+    ///
+    /// Constructs the IR cannot represent yet, such as indexing or top-level
+    /// variables, are reported rather than lowered to a made-up value:
+    ///
+    /// ```rust
+    /// use zast::{parser::ZastParser, zast_ir::ZastIREmitter};
+    ///
+    /// let program = ZastParser::from_source("fn f(x: i32): i32 { x.len }").unwrap();
+    /// let errors = ZastIREmitter::new().emit(&program).unwrap_err();
+    /// assert_eq!(errors.errors()[0].get_error_code(), "E0045");
+    ///
+    /// let program = ZastParser::from_source("const limit: i32 = 8;").unwrap();
+    /// assert!(ZastIREmitter::new().emit(&program).is_err());
+    /// ```
+    pub fn emit(&mut self, program: &ZastProgram) -> Result<ZastIRProgram, ZastErrorCollector> {
         let mut instructions = Vec::new();
 
+        // collected up front so calls can precede the callee's declaration
         for stmt in &program.body {
-            if let Some(instr) = self.emit_statement(&stmt.node) {
+            match &stmt.node {
+                Stmt::FunctionDeclaration {
+                    name,
+                    parameters,
+                    return_type,
+                    ..
+                } => {
+                    let ret_ty = self.return_type(stmt.span, return_type);
+                    self.function_return_types.insert(name.clone(), ret_ty);
+                    self.function_params.insert(
+                        name.clone(),
+                        parameters.iter().map(|param| param.name.clone()).collect(),
+                    );
+                }
+                Stmt::EnumDeclaration { name, variants } => {
+                    self.enum_variants.insert(name.clone(), variants.clone());
+                }
+                _ => {}
+            }
+        }
+
+        for stmt in &program.body {
+            if let Some(instr) = self.emit_statement(stmt) {
                 instructions.push(instr);
             }
         }
 
-        ZastIRProgram { instructions }
+        if self.errors.has_errors() {
+            Err(mem::take(&mut self.errors))
+        } else {
+            Ok(ZastIRProgram { instructions })
+        }
     }
 
-    fn emit_statement(&self, stmt: &Stmt) -> Option<ZastIRInstruction> {
-        match stmt {
+    /// Lowers a top-level statement. Only functions produce instructions, each
+    /// numbering its temporaries from `%0`.
+    ///
+    /// ```rust
+    /// use zast::{parser::ZastParser, zast_ir::ZastIREmitter};
+    ///
+    /// let src = "fn f(a: i32): i32 { return a * 2; }\nfn g(b: i32): i32 { return b - 1; }";
+    /// let ir = ZastIREmitter::new().emit(&ZastParser::from_source(src).unwrap()).unwrap();
+    /// let ir = ir.to_string();
+    ///
+    /// assert!(ir.contains("%0: i32 = mul a, 2"));
+    /// assert!(ir.contains("%0: i32 = sub b, 1"));
+    /// ```
+    fn emit_statement(&mut self, stmt: &Statement) -> Option<ZastIRInstruction> {
+        match &stmt.node {
             Stmt::FunctionDeclaration {
                 name,
                 parameters,
                 return_type,
                 body,
             } => {
-                let params = parameters
+                // temporaries are numbered per function, starting at %0
+                self.next_temp = 0;
+                self.temp_types.clear();
+                self.local_types.clear();
+
+                let params: Vec<(String, ValueType)> = parameters
                     .iter()
-                    .map(|p| {
-                        (
-                            p.name.clone(),
-                            ValueType::from_annotated_type(p.annotated_type.clone()),
-                        )
-                    })
+                    .map(|p| (p.name.clone(), self.binding_type(p.span, &p.annotated_type)))
                     .collect();
+                for (name, ty) in &params {
+                    self.local_types.insert(name.clone(), ty.clone());
+                }
 
-                let ret_ty = match return_type {
-                    ReturnType::Void => ValueType::Void,
-                    ReturnType::Type(t) => ValueType::from_annotated_type(t.clone()),
-                };
+                let ret_ty = self.return_type(stmt.span, return_type);
+
+                let mut body_instructions = Vec::new();
+                if let Stmt::BlockStatement { statements, tail } = &body.node
+                    && let Some(value) =
+                        self.emit_block(statements, tail.as_deref(), &mut body_instructions)
+                {
+                    body_instructions.push(ZastIRInstruction::Return(Some(value)));
+                }
 
                 Some(ZastIRInstruction::FunctionDecl {
                     name: name.clone(),
                     params,
                     return_type: ret_ty,
-                    body: vec![], // empty for now
+                    body: body_instructions,
                 })
             }
+            // only contribute names and types, collected by `emit`
+            Stmt::EnumDeclaration { .. } | Stmt::StructDeclaration { .. } | Stmt::Import { .. } => {
+                None
+            }
+            // the IR has no globals to hold them yet
+            Stmt::VariableDeclaration { .. } => {
+                self.unsupported(stmt.span, "Top-level variable declaration");
+                None
+            }
+            _ => {
+                self.unsupported(stmt.span, "Top-level statement");
+                None
+            }
+        }
+    }
+
+    /// Lowers a statement inside a function body into `out`. Control flow is
+    /// lowered to labels, jumps and conditional branches.
+    ///
+    /// ```rust
+    /// use zast::{parser::ZastParser, zast_ir::ZastIREmitter};
+    ///
+    /// let src = "fn min(a: i32, b: i32): i32 { if a < b { return a; } else { return b; } }";
+    /// let ir = ZastIREmitter::new().emit(&ZastParser::from_source(src).unwrap()).unwrap();
+    ///
+    /// assert_eq!(
+    ///     ir.to_string().lines().collect::<Vec<_>>(),
+    ///     [
+    ///         "fn min(a: i32, b: i32): i32 {",
+    ///         "    %0: bool = lt a, b",
+    ///         "    br %0, then0, else1",
+    ///         "then0:",
+    ///         "    ret a",
+    ///         "    jmp endif2",
+    ///         "else1:",
+    ///         "    ret b",
+    ///         "    jmp endif2",
+    ///         "endif2:",
+    ///         "}",
+    ///     ]
+    /// );
+    ///
+    /// // a loop's condition is re-evaluated under its header on every iteration
+    /// let src = "fn f(n: i32): void { let i: i32 = 0; while i < n { i = i + 1; } }";
+    /// let ir = ZastIREmitter::new().emit(&ZastParser::from_source(src).unwrap()).unwrap();
+    ///
+    /// assert_eq!(
+    ///     ir.to_string().lines().collect::<Vec<_>>(),
+    ///     [
+    ///         "fn f(n: i32): void {",
+    ///         "    let i: i32 = 0",
+    ///         "while0:",
+    ///         "    %0: bool = lt i, n",
+    ///         "    br %0, body1, endwhile2",
+    ///         "body1:",
+    ///         "    %1: i32 = add i, 1",
+    ///         "    i = %1",
+    ///         "    jmp while0",
+    ///         "endwhile2:",
+    ///         "}",
+    ///     ]
+    /// );
+    /// ```
+    fn emit_body_statement(&mut self, stmt: &Statement, out: &mut Vec<ZastIRInstruction>) {
+        match &stmt.node {
+            Stmt::VariableDeclaration {
+                mutable,
+                identifier,
+                annotated_type,
+                value,
+            } => {
+                let inferred = self.expr_type(value);
+                let value = self.emit_expr(value, out);
+                let val_type = match annotated_type {
+                    Some(t) => self.binding_type(stmt.span, t),
+                    None => inferred.unwrap_or_else(|| self.value_type(&value)),
+                };
+                self.local_types
+                    .insert(identifier.clone(), val_type.clone());
+
+                out.push(ZastIRInstruction::Declare {
+                    name: identifier.clone(),
+                    val_type,
+                    value,
+                    mutable: *mutable,
+                });
+            }
+            Stmt::Expression { expression } => {
+                self.emit_expr(expression, out);
+            }
+            Stmt::Return { value } => {
+                let value = value.as_ref().map(|v| self.emit_expr(v, out));
+                out.push(ZastIRInstruction::Return(value));
+            }
+            Stmt::BlockStatement { statements, tail } => {
+                self.emit_block(statements, tail.as_deref(), out);
+            }
+            Stmt::EnumDeclaration { name, variants } => {
+                self.enum_variants.insert(name.clone(), variants.clone());
+            }
+            Stmt::If {
+                condition,
+                then_branch,
+                else_branch,
+            } => {
+                let cond = self.emit_expr(condition, out);
+                let then_label = self.new_label("then");
+                let else_label = else_branch.as_ref().map(|_| self.new_label("else"));
+                let merge_label = self.new_label("endif");
+
+                out.push(ZastIRInstruction::BranchIf {
+                    cond,
+                    then_label: then_label.clone(),
+                    else_label: else_label.clone().unwrap_or_else(|| merge_label.clone()),
+                });
+
+                out.push(ZastIRInstruction::Label(then_label));
+                self.emit_body_statement(then_branch, out);
+                out.push(ZastIRInstruction::Jump(merge_label.clone()));
+
+                if let (Some(else_branch), Some(else_label)) = (else_branch, else_label) {
+                    out.push(ZastIRInstruction::Label(else_label));
+                    self.emit_body_statement(else_branch, out);
+                    out.push(ZastIRInstruction::Jump(merge_label.clone()));
+                }
+
+                out.push(ZastIRInstruction::Label(merge_label));
+            }
+            Stmt::While { condition, body } => {
+                let header_label = self.new_label("while");
+                let body_label = self.new_label("body");
+                let exit_label = self.new_label("endwhile");
+
+                // the condition lives under the header so every iteration re-evaluates it
+                out.push(ZastIRInstruction::Label(header_label.clone()));
+                let cond = self.emit_expr(condition, out);
+                out.push(ZastIRInstruction::BranchIf {
+                    cond,
+                    then_label: body_label.clone(),
+                    else_label: exit_label.clone(),
+                });
+
+                out.push(ZastIRInstruction::Label(body_label));
+                self.emit_body_statement(body, out);
+                out.push(ZastIRInstruction::Jump(header_label));
+
+                out.push(ZastIRInstruction::Label(exit_label));
+            }
+            _ => {}
+        }
+    }
+
+    /// Lowers a block's statements straight into `out`, returning the value of
+    /// its tail expression if it has one.
+    ///
+    /// Blocks don't introduce IR boundaries: nested scopes are flattened into
+    /// the enclosing function body, and only the local types they declare are
+    /// dropped again on exit.
+    ///
+    /// ```rust
+    /// use zast::{parser::ZastParser, zast_ir::ZastIREmitter};
+    ///
+    /// let src = "fn f(): void { { const x: i32 = 1; } }";
+    /// let ir = ZastIREmitter::new().emit(&ZastParser::from_source(src).unwrap()).unwrap();
+    ///
+    /// assert_eq!(
+    ///     ir.to_string().lines().collect::<Vec<_>>(),
+    ///     ["fn f(): void {", "    const x: i32 = 1", "}"]
+    /// );
+    /// ```
+    fn emit_block(
+        &mut self,
+        statements: &[Box<Statement>],
+        tail: Option<&Expression>,
+        out: &mut Vec<ZastIRInstruction>,
+    ) -> Option<ZastIRValue> {
+        let enclosing_types = self.local_types.clone();
+
+        for stmt in statements {
+            self.emit_body_statement(stmt, out);
+        }
+        let value = tail.map(|tail| self.emit_expr(tail, out));
+
+        self.local_types = enclosing_types;
+        value
+    }
+
+    /// Lowers `expr` into `out`, returning the value holding its result.
+    /// Every operation writes a fresh temporary; literals and identifiers are
+    /// used in place.
+    ///
+    /// ```rust
+    /// use zast::{
+    ///     parser::ZastParser,
+    ///     zast_ir::{ZastIREmitter, ir_instructions::ZastIRInstruction, ir_values::ZastIRValue},
+    /// };
+    ///
+    /// let src = "fn g(x: i32, y: i32): i32 { return x + y; }\nfn f(): i32 { return g(1, 2); }";
+    /// let ir = ZastIREmitter::new().emit(&ZastParser::from_source(src).unwrap()).unwrap();
+    ///
+    /// let ZastIRInstruction::FunctionDecl { body, .. } = &ir.instructions[1] else {
+    ///     unreachable!()
+    /// };
+    /// let ZastIRInstruction::Call { dest: Some(dest), name, args, .. } = &body[0] else {
+    ///     unreachable!()
+    /// };
+    /// assert_eq!(name, "g");
+    /// assert!(matches!(args[..], [ZastIRValue::Int(1), ZastIRValue::Int(2)]));
+    /// assert!(matches!(
+    ///     body[1],
+    ///     ZastIRInstruction::Return(Some(ZastIRValue::Temporary(t))) if t == *dest
+    /// ));
+    /// ```
+    fn emit_expr(&mut self, expr: &Expression, out: &mut Vec<ZastIRInstruction>) -> ZastIRValue {
+        match &expr.node {
+            Expr::IntegerLiteral { value, .. } => ZastIRValue::Int(*value),
+            Expr::FloatLiteral { value, .. } => ZastIRValue::Float(*value),
+            Expr::CharLiteral(value) => ZastIRValue::Int(*value as i64),
+            Expr::StringLiteral(value) => ZastIRValue::Str(value.clone()),
+            Expr::Identifier(name) => ZastIRValue::Reference(name.clone()),
+            // a variant is represented by its index in the declaration
+            Expr::EnumVariant { enum_name, variant } => self
+                .enum_variants
+                .get(enum_name)
+                .and_then(|variants| variants.iter().position(|v| v == variant))
+                .map_or(ZastIRValue::Null, |index| ZastIRValue::Int(index as i64)),
+            Expr::Negate(operand) => self.emit_unary(UnaryOp::Negate, expr, operand, out),
+            Expr::Dereference(operand) => self.emit_unary(UnaryOp::Deref, expr, operand, out),
+            Expr::Address(operand) => self.emit_unary(UnaryOp::Address, expr, operand, out),
+            Expr::Cast {
+                expr: operand,
+                target,
+            } => self.emit_cast(expr, operand, target, out),
+            Expr::BinaryExpression {
+                left,
+                operator,
+                right,
+            } => {
+                let left = self.emit_expr(left, out);
+                let right = self.emit_expr(right, out);
+                let Some(op) = Self::binary_op(*operator) else {
+                    self.unsupported(expr.span, &format!("Operator '{:?}'", operator));
+                    return ZastIRValue::Null;
+                };
+
+                let val_type = match self.expr_type(expr) {
+                    Some(val_type) => val_type,
+                    None if op.is_comparison() => ValueType::Bool,
+                    None => self.value_type(&left),
+                };
+                let dest = self.new_temp(val_type.clone());
+
+                out.push(ZastIRInstruction::BinaryOp {
+                    dest,
+                    op,
+                    left,
+                    right,
+                    val_type,
+                    span: Some(expr.span),
+                });
+                ZastIRValue::Temporary(dest)
+            }
+            Expr::Assignment { target, value } => {
+                let value = self.emit_expr(value, out);
+                let Expr::Identifier(name) = &target.node else {
+                    // stores through pointers and places aren't representable yet
+                    self.unsupported(target.span, "Assignment to this target");
+                    return ZastIRValue::Null;
+                };
+
+                out.push(ZastIRInstruction::Assign {
+                    name: name.clone(),
+                    value,
+                });
+                ZastIRValue::Reference(name.clone())
+            }
+            Expr::Call { callee, arguments } => {
+                // evaluated in source order, then passed in parameter order
+                let values: Vec<ZastIRValue> = arguments
+                    .iter()
+                    .map(|argument| self.emit_expr(&argument.value, out))
+                    .collect();
+                let Expr::Identifier(name) = &callee.node else {
+                    // indirect calls through computed callees aren't representable yet
+                    self.unsupported(callee.span, "Indirect call");
+                    return ZastIRValue::Null;
+                };
+                let args = self.order_arguments(name, arguments, values);
+
+                let return_type = match (self.expr_type(expr), self.local_types.get(name)) {
+                    (Some(return_type), _) => return_type,
+                    // a call through a function-typed parameter or local
+                    (None, Some(ValueType::Function { return_type, .. })) => {
+                        (**return_type).clone()
+                    }
+                    (None, _) => self
+                        .function_return_types
+                        .get(name)
+                        .cloned()
+                        .unwrap_or(ValueType::Void),
+                };
+                let dest = match return_type {
+                    ValueType::Void => None,
+                    return_type => Some(self.new_temp(return_type)),
+                };
+
+                out.push(ZastIRInstruction::Call {
+                    dest,
+                    name: name.clone(),
+                    args,
+                    span: Some(expr.span),
+                });
+                dest.map_or(ZastIRValue::Null, ZastIRValue::Temporary)
+            }
+            // indexing and field access need memory instructions the IR
+            // doesn't have yet
+            Expr::Index { .. } => {
+                self.unsupported(expr.span, "Indexing");
+                ZastIRValue::Null
+            }
+            Expr::FieldAccess { .. } => {
+                self.unsupported(expr.span, "Field access");
+                ZastIRValue::Null
+            }
+            Expr::Ternary {
+                condition,
+                then_expr,
+                else_expr,
+            } => self.emit_ternary(expr, condition, then_expr, else_expr, out),
+        }
+    }
+
+    /// Lowers `condition ? then_expr : else_expr` into a branch whose arms
+    /// each assign their value to a local holding the result, so only the
+    /// taken arm is evaluated. The local's name contains a `.`, which keeps it
+    /// apart from user identifiers.
+    ///
+    /// ```rust
+    /// use zast::{parser::ZastParser, zast_ir::ZastIREmitter};
+    ///
+    /// let program = ZastParser::from_source("fn f(c: bool, x: i32): i32 { c ? x : 0 }").unwrap();
+    /// let ir = ZastIREmitter::new().emit(&program).unwrap().to_string();
+    ///
+    /// assert_eq!(
+    ///     ir.lines().collect::<Vec<_>>(),
+    ///     [
+    ///         "fn f(c: bool, x: i32): i32 {",
+    ///         "    let ternary0.result: i32",
+    ///         "    br c, then1, else2",
+    ///         "then1:",
+    ///         "    ternary0.result = x",
+    ///         "    jmp endternary3",
+    ///         "else2:",
+    ///         "    ternary0.result = 0",
+    ///         "    jmp endternary3",
+    ///         "endternary3:",
+    ///         "    ret ternary0.result",
+    ///         "}",
+    ///     ]
+    /// );
+    /// ```
+    fn emit_ternary(
+        &mut self,
+        expr: &Expression,
+        condition: &Expression,
+        then_expr: &Expression,
+        else_expr: &Expression,
+        out: &mut Vec<ZastIRInstruction>,
+    ) -> ZastIRValue {
+        let cond = self.emit_expr(condition, out);
+        let result = format!("{}.result", self.new_label("ternary"));
+        let then_label = self.new_label("then");
+        let else_label = self.new_label("else");
+        let merge_label = self.new_label("endternary");
+
+        // the result is declared ahead of the branch once an arm has typed it
+        let declare_at = out.len();
+        out.push(ZastIRInstruction::BranchIf {
+            cond,
+            then_label: then_label.clone(),
+            else_label: else_label.clone(),
+        });
+
+        out.push(ZastIRInstruction::Label(then_label));
+        let then_value = self.emit_expr(then_expr, out);
+        let val_type = self
+            .expr_type(expr)
+            .unwrap_or_else(|| self.value_type(&then_value));
+        out.push(ZastIRInstruction::Assign {
+            name: result.clone(),
+            value: then_value,
+        });
+        out.push(ZastIRInstruction::Jump(merge_label.clone()));
+
+        out.push(ZastIRInstruction::Label(else_label));
+        let else_value = self.emit_expr(else_expr, out);
+        out.push(ZastIRInstruction::Assign {
+            name: result.clone(),
+            value: else_value,
+        });
+        out.push(ZastIRInstruction::Jump(merge_label.clone()));
+
+        out.push(ZastIRInstruction::Label(merge_label));
+
+        self.local_types.insert(result.clone(), val_type.clone());
+        out.insert(
+            declare_at,
+            ZastIRInstruction::DeclareUninit {
+                name: result.clone(),
+                val_type,
+            },
+        );
+        ZastIRValue::Reference(result)
+    }
+
+    /// Reports `construct` at `span` as having no IR lowering. The emitted
+    /// program is discarded, so the value returned in its place never escapes.
+    fn unsupported(&mut self, span: Span, construct: &str) {
+        self.errors.add_error(ZastError::UnsupportedInIR {
+            span,
+            construct: construct.to_string(),
+        });
+    }
+
+    /// Reorders already-lowered argument `values` into the parameter order of
+    /// the function `name`, placing named arguments by parameter name. Sema
+    /// has already checked the names, so unknown callees keep source order.
+    fn order_arguments(
+        &self,
+        name: &str,
+        arguments: &[Argument],
+        values: Vec<ZastIRValue>,
+    ) -> Vec<ZastIRValue> {
+        let param_names = match self.function_params.get(name) {
+            Some(names) if !self.local_types.contains_key(name) => names,
+            _ => return values,
+        };
+
+        let mut ordered = values.clone();
+        for (position, (argument, value)) in arguments.iter().zip(values).enumerate() {
+            let index = argument
+                .name
+                .as_ref()
+                .and_then(|name| param_names.iter().position(|param| param == name))
+                .unwrap_or(position);
+            if let Some(slot) = ordered.get_mut(index) {
+                *slot = value;
+            }
+        }
+        ordered
+    }
+
+    fn emit_unary(
+        &mut self,
+        op: UnaryOp,
+        expr: &Expression,
+        operand: &Expression,
+        out: &mut Vec<ZastIRInstruction>,
+    ) -> ZastIRValue {
+        let operand = self.emit_expr(operand, out);
+
+        if matches!(op, UnaryOp::Negate)
+            && let Some(negated) = Self::negate_constant(&operand)
+        {
+            return negated;
+        }
+
+        let val_type = self.expr_type(expr).unwrap_or_else(|| {
+            let operand_type = self.value_type(&operand);
+            match op {
+                UnaryOp::Negate | UnaryOp::Cast => operand_type,
+                UnaryOp::Address => ValueType::Pointer(Box::new(operand_type)),
+                UnaryOp::Deref => match operand_type {
+                    ValueType::Pointer(pointee) => *pointee,
+                    other => other,
+                },
+            }
+        });
+        let dest = self.new_temp(val_type.clone());
+
+        out.push(ZastIRInstruction::UnaryOp {
+            dest,
+            op,
+            operand,
+            val_type,
+            span: Some(expr.span),
+        });
+        ZastIRValue::Temporary(dest)
+    }
+
+    /// Lowers `operand as target` into a `cast` producing the target type.
+    fn emit_cast(
+        &mut self,
+        expr: &Expression,
+        operand: &Expression,
+        target: &AnnotatedType,
+        out: &mut Vec<ZastIRInstruction>,
+    ) -> ZastIRValue {
+        let operand = self.emit_expr(operand, out);
+        let val_type = self
+            .expr_type(expr)
+            .or_else(|| ValueType::try_from_annotated_type(target))
+            .unwrap_or_else(|| self.value_type(&operand));
+        let dest = self.new_temp(val_type.clone());
+
+        out.push(ZastIRInstruction::UnaryOp {
+            dest,
+            op: UnaryOp::Cast,
+            operand,
+            val_type,
+            span: Some(expr.span),
+        });
+        ZastIRValue::Temporary(dest)
+    }
+
+    /// Folds the negation of a constant, so `-5` lowers to `Int(-5)` rather
+    /// than a `neg`. Returns `None` for non-constants.
+    ///
+    /// `i64::MIN` cannot be written as a literal: the lexer rejects
+    /// `9223372036854775808` as too large before any minus applies to it.
+    /// The negation is still checked, so folding a constant `i64::MIN` would
+    /// leave a `neg` rather than overflow.
+    ///
+    /// ```rust
+    /// use zast::{parser::ZastParser, pipeline::ZastPipeline, zast_ir::ZastIREmitter};
+    ///
+    /// let src = "fn f(): i64 { -9223372036854775807 }";
+    /// let ir = ZastIREmitter::new().emit(&ZastParser::from_source(src).unwrap()).unwrap();
+    /// assert!(ir.to_string().contains("ret -9223372036854775807"));
+    ///
+    /// for min in ["-9223372036854775808", "-9223372036854775808i64"] {
+    ///     let errors = ZastPipeline::new().compile(&format!("fn f(): i64 {{ {} }}", min)).errors;
+    ///     assert_eq!(errors.errors()[0].get_error_code(), "E0040");
+    /// }
+    /// ```
+    fn negate_constant(operand: &ZastIRValue) -> Option<ZastIRValue> {
+        match operand {
+            ZastIRValue::Int(value) => value.checked_neg().map(ZastIRValue::Int),
+            ZastIRValue::Float(value) => Some(ZastIRValue::Float(-value)),
             _ => None,
         }
     }
+
+    fn binary_op(operator: TokenKind) -> Option<BinaryOp> {
+        match operator {
+            TokenKind::Plus => Some(BinaryOp::Add),
+            TokenKind::Minus => Some(BinaryOp::Sub),
+            TokenKind::Multiply => Some(BinaryOp::Mul),
+            TokenKind::Divide => Some(BinaryOp::Div),
+            TokenKind::DoubleStar => Some(BinaryOp::Pow),
+            TokenKind::Equal => Some(BinaryOp::Eq),
+            TokenKind::NotEqual => Some(BinaryOp::NotEq),
+            TokenKind::Less => Some(BinaryOp::Lt),
+            TokenKind::LessEqual => Some(BinaryOp::LtEq),
+            TokenKind::Greater => Some(BinaryOp::Gt),
+            TokenKind::GreaterEqual => Some(BinaryOp::GtEq),
+            TokenKind::LogicalAnd => Some(BinaryOp::And),
+            TokenKind::LogicalOr => Some(BinaryOp::Or),
+            TokenKind::Ampersand => Some(BinaryOp::BitAnd),
+            TokenKind::Caret => Some(BinaryOp::BitXor),
+            TokenKind::Pipe => Some(BinaryOp::BitOr),
+            TokenKind::Shl => Some(BinaryOp::Shl),
+            TokenKind::Shr => Some(BinaryOp::Shr),
+            _ => None,
+        }
+    }
+
+    /// Returns a label that is unique within this emitter, e.g. `then0`.
+    /// `hint` only makes the rendered IR easier to follow.
+    pub fn new_label(&mut self, hint: &str) -> String {
+        let label = format!("{}{}", hint, self.next_label);
+        self.next_label += 1;
+        label
+    }
+
+    fn new_temp(&mut self, val_type: ValueType) -> usize {
+        let dest = self.next_temp;
+        self.next_temp += 1;
+        self.temp_types.insert(dest, val_type);
+        dest
+    }
+
+    /// Returns the type sema resolved for the binding declared at `span`,
+    /// falling back to converting `annotated_type` directly. Unknown types
+    /// become `void`; sema has already reported them.
+    fn binding_type(&self, span: Span, annotated_type: &AnnotatedType) -> ValueType {
+        self.binding_types
+            .get(&span)
+            .cloned()
+            .or_else(|| ValueType::try_from_annotated_type(annotated_type))
+            .unwrap_or(ValueType::Void)
+    }
+
+    /// Returns the return type of the function declared at `span`, like
+    /// [`ZastIREmitter::binding_type`].
+    fn return_type(&self, span: Span, return_type: &ReturnType) -> ValueType {
+        match (self.binding_types.get(&span), return_type) {
+            (Some(ValueType::Function { return_type, .. }), _) => (**return_type).clone(),
+            (_, ReturnType::Void) => ValueType::Void,
+            (_, ReturnType::Type(t)) => {
+                ValueType::try_from_annotated_type(t).unwrap_or(ValueType::Void)
+            }
+        }
+    }
+
+    /// Returns the type sema inferred for `expr`, if emitting a [`TypedProgram`].
+    fn expr_type(&self, expr: &Expression) -> Option<ValueType> {
+        self.expr_types.get(&expr.id).cloned()
+    }
+
+    fn value_type(&self, value: &ZastIRValue) -> ValueType {
+        match value {
+            ZastIRValue::Int(_) => self.config.default_int_type.clone(),
+            ZastIRValue::Float(_) => ValueType::Float {
+                width: FloatWidth::F64,
+            },
+            ZastIRValue::Bool(_) => ValueType::Bool,
+            ZastIRValue::Str(_) => ValueType::Pointer(Box::new(ValueType::Integer {
+                bits: 8,
+                unsigned: true,
+            })),
+            ZastIRValue::Reference(name) => self
+                .local_types
+                .get(name)
+                .cloned()
+                .unwrap_or(ValueType::Void),
+            ZastIRValue::Temporary(n) => self.temp_types.get(n).cloned().unwrap_or(ValueType::Void),
+            ZastIRValue::Null => ValueType::Void,
+        }
+    }
+}
+
+impl Default for ZastIREmitter {
+    fn default() -> Self {
+        Self::new()
+    }
 }
@@ -1,5 +1,32 @@
-use crate::{types::ValueType, zast_ir::ir_values::ZastIRValue};
+use core::fmt;
 
+use crate::{lexer::tokens::Span, types::ValueType, zast_ir::ir_values::ZastIRValue};
+
+/// A single IR instruction. Those lowered from a source expression carry its
+/// span, so passes over the IR can report located diagnostics.
+///
+/// ```rust
+/// use zast::{
+///     lexer::tokens::Span,
+///     parser::ZastParser,
+///     zast_ir::{ZastIREmitter, ir_instructions::ZastIRInstruction},
+/// };
+///
+/// let program = ZastParser::from_source("fn f(a: i32, b: i32): i32 { a / b }").unwrap();
+/// let ir = ZastIREmitter::new().emit(&program).unwrap();
+///
+/// let ZastIRInstruction::FunctionDecl { body, .. } = &ir.instructions[0] else {
+///     unreachable!()
+/// };
+/// let ZastIRInstruction::BinaryOp { span, .. } = &body[0] else {
+///     unreachable!()
+/// };
+/// assert_eq!(
+///     *span,
+///     Some(Span { ln_start: 1, ln_end: 1, col_start: 29, col_end: 33 })
+/// );
+/// ```
+#[derive(Debug, Clone)]
 pub enum ZastIRInstruction {
     // variable declaration
     Declare {
@@ -9,6 +36,13 @@ pub enum ZastIRInstruction {
         mutable: bool,
     },
 
+    // mutable variable declaration without a value, assigned on every path
+    // before it is read
+    DeclareUninit {
+        name: String,
+        val_type: ValueType,
+    },
+
     // assignment
     Assign {
         name: String,
@@ -22,6 +56,8 @@ pub enum ZastIRInstruction {
         left: ZastIRValue,
         right: ZastIRValue,
         val_type: ValueType,
+        // source expression, for diagnostics from IR passes
+        span: Option<Span>,
     },
 
     // unary op
@@ -30,6 +66,7 @@ pub enum ZastIRInstruction {
         op: UnaryOp,
         operand: ZastIRValue,
         val_type: ValueType,
+        span: Option<Span>,
     },
 
     // function declaration
@@ -45,25 +82,248 @@ pub enum ZastIRInstruction {
         dest: Option<usize>, // None if return is void
         name: String,
         args: Vec<ZastIRValue>,
+        span: Option<Span>,
     },
 
     // return
     Return(Option<ZastIRValue>),
+
+    // jump target
+    Label(String),
+
+    // unconditional jump
+    Jump(String),
+
+    // conditional jump on a bool value
+    BranchIf {
+        cond: ZastIRValue,
+        then_label: String,
+        else_label: String,
+    },
 }
 
+#[derive(Debug, Clone)]
 pub enum BinaryOp {
     Add,
     Sub,
     Mul,
     Div,
+    Pow,
+    Eq,
+    NotEq,
+    Lt,
+    LtEq,
+    Gt,
+    GtEq,
+    And,
+    Or,
+    BitAnd,
+    BitXor,
+    BitOr,
+    Shl,
+    Shr,
+}
+
+impl BinaryOp {
+    // comparison and logical ops always produce a bool
+    pub fn is_comparison(&self) -> bool {
+        matches!(
+            self,
+            Self::Eq
+                | Self::NotEq
+                | Self::Lt
+                | Self::LtEq
+                | Self::Gt
+                | Self::GtEq
+                | Self::And
+                | Self::Or
+        )
+    }
 }
 
+#[derive(Debug, Clone)]
 pub enum UnaryOp {
     Negate,
     Deref,
     Address,
+    Cast,
 }
 
+/// The IR of a whole program, one [`ZastIRInstruction::FunctionDecl`] per
+/// function.
+///
+/// ```rust
+/// use zast::{parser::ZastParser, zast_ir::ZastIREmitter};
+///
+/// let program = ZastParser::from_source("fn main(): i32 { return 1; }").unwrap();
+/// let ir = ZastIREmitter::new().emit(&program).unwrap();
+///
+/// let debug = format!("{:?}", ir);
+/// assert!(debug.starts_with("ZastIRProgram { instructions: [FunctionDecl { name: \"main\""));
+/// assert!(debug.contains("Return(Some(Int(1)))"));
+/// ```
+#[derive(Debug, Clone)]
 pub struct ZastIRProgram {
     pub instructions: Vec<ZastIRInstruction>,
 }
+
+impl fmt::Display for BinaryOp {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let name = match self {
+            Self::Add => "add",
+            Self::Sub => "sub",
+            Self::Mul => "mul",
+            Self::Div => "div",
+            Self::Pow => "pow",
+            Self::Eq => "eq",
+            Self::NotEq => "ne",
+            Self::Lt => "lt",
+            Self::LtEq => "le",
+            Self::Gt => "gt",
+            Self::GtEq => "ge",
+            Self::And => "and",
+            Self::Or => "or",
+            Self::BitAnd => "band",
+            Self::BitXor => "bxor",
+            Self::BitOr => "bor",
+            Self::Shl => "shl",
+            Self::Shr => "shr",
+        };
+        write!(f, "{}", name)
+    }
+}
+
+impl fmt::Display for UnaryOp {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let name = match self {
+            Self::Negate => "neg",
+            Self::Deref => "deref",
+            Self::Address => "addr",
+            Self::Cast => "cast",
+        };
+        write!(f, "{}", name)
+    }
+}
+
+/// Renders one instruction per line, e.g. `%0: i32 = add a, 1`. Function
+/// bodies are indented beneath their signature, with labels outdented so
+/// jump targets stand out.
+///
+/// ```rust
+/// use zast::{
+///     types::ValueType,
+///     zast_ir::{ir_instructions::ZastIRInstruction, ir_values::ZastIRValue},
+/// };
+///
+/// let function = ZastIRInstruction::FunctionDecl {
+///     name: String::from("f"),
+///     params: vec![(String::from("c"), ValueType::Bool)],
+///     return_type: ValueType::Void,
+///     body: vec![
+///         ZastIRInstruction::BranchIf {
+///             cond: ZastIRValue::Reference(String::from("c")),
+///             then_label: String::from("then0"),
+///             else_label: String::from("end1"),
+///         },
+///         ZastIRInstruction::Label(String::from("then0")),
+///         ZastIRInstruction::Jump(String::from("end1")),
+///         ZastIRInstruction::Label(String::from("end1")),
+///         ZastIRInstruction::Return(None),
+///     ],
+/// };
+///
+/// assert_eq!(
+///     function.to_string().lines().collect::<Vec<_>>(),
+///     [
+///         "fn f(c: bool): void {",
+///         "    br c, then0, end1",
+///         "then0:",
+///         "    jmp end1",
+///         "end1:",
+///         "    ret",
+///         "}",
+///     ]
+/// );
+/// ```
+impl fmt::Display for ZastIRInstruction {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Declare {
+                name,
+                val_type,
+                value,
+                mutable,
+            } => {
+                let keyword = if *mutable { "let" } else { "const" };
+                write!(f, "{} {}: {} = {}", keyword, name, val_type, value)
+            }
+            Self::DeclareUninit { name, val_type } => write!(f, "let {}: {}", name, val_type),
+            Self::Assign { name, value } => write!(f, "{} = {}", name, value),
+            Self::BinaryOp {
+                dest,
+                op,
+                left,
+                right,
+                val_type,
+                ..
+            } => write!(f, "%{}: {} = {} {}, {}", dest, val_type, op, left, right),
+            Self::UnaryOp {
+                dest,
+                op,
+                operand,
+                val_type,
+                ..
+            } => write!(f, "%{}: {} = {} {}", dest, val_type, op, operand),
+            Self::FunctionDecl {
+                name,
+                params,
+                return_type,
+                body,
+            } => {
+                let params: Vec<String> = params
+                    .iter()
+                    .map(|(name, ty)| format!("{}: {}", name, ty))
+                    .collect();
+                writeln!(f, "fn {}({}): {} {{", name, params.join(", "), return_type)?;
+
+                for instr in body {
+                    match instr {
+                        Self::Label(_) => writeln!(f, "{}", instr)?,
+                        _ => writeln!(f, "    {}", instr)?,
+                    }
+                }
+                write!(f, "}}")
+            }
+            Self::Call {
+                dest, name, args, ..
+            } => {
+                let args: Vec<String> = args.iter().map(|a| a.to_string()).collect();
+                if let Some(dest) = dest {
+                    write!(f, "%{} = ", dest)?;
+                }
+                write!(f, "call {}({})", name, args.join(", "))
+            }
+            Self::Return(Some(value)) => write!(f, "ret {}", value),
+            Self::Return(None) => write!(f, "ret"),
+            Self::Label(label) => write!(f, "{}:", label),
+            Self::Jump(label) => write!(f, "jmp {}", label),
+            Self::BranchIf {
+                cond,
+                then_label,
+                else_label,
+            } => write!(f, "br {}, {}, {}", cond, then_label, else_label),
+        }
+    }
+}
+
+impl fmt::Display for ZastIRProgram {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (i, instr) in self.instructions.iter().enumerate() {
+            if i > 0 {
+                writeln!(f)?;
+            }
+            writeln!(f, "{}", instr)?;
+        }
+        Ok(())
+    }
+}
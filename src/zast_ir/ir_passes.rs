@@ -0,0 +1,398 @@
+use std::collections::{HashMap, HashSet};
+
+use crate::{
+    config::ZastConfig,
+    error_handler::{ZastErrorCollector, zast_errors::ZastError},
+    lexer::tokens::Span,
+    types::{FloatWidth, ValueType},
+    zast_ir::{
+        ir_instructions::{BinaryOp, ZastIRInstruction, ZastIRProgram},
+        ir_values::ZastIRValue,
+    },
+};
+
+/// Evaluates every `BinaryOp` whose operands are constants, removes it, and
+/// substitutes the result wherever its temporary was read.
+///
+/// Folding cascades, so `1 + 2 * 3` reduces to `Int(7)`. Integer division by
+/// a constant zero is left in place and reported as
+/// [`ZastError::DivisionByZero`] at the division's span in the returned
+/// collector.
+///
+/// Integer results are computed for the operation's type. One that doesn't
+/// fit wraps around to the type's width, as unchecked arithmetic would at run
+/// time, so `200u8 + 100u8` folds to `Int(44)`. Shift amounts wrap to the
+/// width as well.
+///
+/// ```rust
+/// use zast::{
+///     parser::ZastParser,
+///     sema::ZastSemanticAnalyzer,
+///     zast_ir::{
+///         ZastIREmitter,
+///         ir_instructions::ZastIRInstruction,
+///         ir_passes::fold_constants,
+///         ir_values::ZastIRValue,
+///     },
+/// };
+///
+/// let src = "fn f(): i32 { return 1 + 2 * 3; }
+///            fn g(): i32 { return 1 / 0; }
+///            fn h(): u8 { return 200u8 + 100u8; }
+///            fn k(): i32 { return 6 ^ 3 | 2 ** 3; }";
+/// let program = ZastParser::from_source(src).unwrap();
+/// let typed = ZastSemanticAnalyzer::new().analyze_typed(program).unwrap();
+/// let mut ir = ZastIREmitter::new().emit_typed(&typed).unwrap();
+/// let errors = fold_constants(&mut ir);
+///
+/// let ZastIRInstruction::FunctionDecl { body, .. } = &ir.instructions[0] else {
+///     unreachable!()
+/// };
+/// assert!(matches!(body[..], [ZastIRInstruction::Return(Some(ZastIRValue::Int(7)))]));
+///
+/// let ZastIRInstruction::FunctionDecl { body, .. } = &ir.instructions[1] else {
+///     unreachable!()
+/// };
+/// assert!(matches!(body[0], ZastIRInstruction::BinaryOp { .. }));
+/// assert_eq!(errors.errors()[0].get_error_code(), "E0027");
+///
+/// let ZastIRInstruction::FunctionDecl { body, .. } = &ir.instructions[2] else {
+///     unreachable!()
+/// };
+/// assert!(matches!(body[..], [ZastIRInstruction::Return(Some(ZastIRValue::Int(44)))]));
+///
+/// let ZastIRInstruction::FunctionDecl { body, .. } = &ir.instructions[3] else {
+///     unreachable!()
+/// };
+/// assert!(matches!(body[..], [ZastIRInstruction::Return(Some(ZastIRValue::Int(13)))]));
+/// ```
+pub fn fold_constants(program: &mut ZastIRProgram) -> ZastErrorCollector {
+    fold_constants_with_config(program, &ZastConfig::default())
+}
+
+/// Like [`fold_constants`], with integer widths of pointer-sized types taken
+/// from `config`. With [`ZastConfig::checked_arithmetic`] set, an integer
+/// result that doesn't fit its type is reported as
+/// [`ZastError::ArithmeticOverflow`] and left in place instead of wrapping.
+///
+/// ```rust
+/// use zast::{
+///     config::ZastConfig,
+///     parser::ZastParser,
+///     sema::ZastSemanticAnalyzer,
+///     zast_ir::{
+///         ZastIREmitter, ir_instructions::ZastIRInstruction,
+///         ir_passes::fold_constants_with_config,
+///     },
+/// };
+///
+/// let src = "fn h(): u8 { return 200u8 + 100u8; }";
+/// let config = ZastConfig::new().checked_arithmetic(true);
+/// let typed = ZastSemanticAnalyzer::with_config(config.clone())
+///     .analyze_typed(ZastParser::from_source(src).unwrap())
+///     .unwrap();
+/// let mut ir = ZastIREmitter::with_config(config.clone()).emit_typed(&typed).unwrap();
+/// let errors = fold_constants_with_config(&mut ir, &config);
+///
+/// assert_eq!(errors.errors()[0].get_error_code(), "E0044");
+/// let ZastIRInstruction::FunctionDecl { body, .. } = &ir.instructions[0] else {
+///     unreachable!()
+/// };
+/// assert!(matches!(body[0], ZastIRInstruction::BinaryOp { .. }));
+/// ```
+pub fn fold_constants_with_config(
+    program: &mut ZastIRProgram,
+    config: &ZastConfig,
+) -> ZastErrorCollector {
+    let mut errors = ZastErrorCollector::from_config(config);
+    fold_instructions(&mut program.instructions, config, &mut errors);
+    errors
+}
+
+fn fold_instructions(
+    instructions: &mut Vec<ZastIRInstruction>,
+    config: &ZastConfig,
+    errors: &mut ZastErrorCollector,
+) {
+    let mut folded: HashMap<usize, ZastIRValue> = HashMap::new();
+
+    instructions.retain_mut(|instr| {
+        match instr {
+            ZastIRInstruction::Declare { value, .. } | ZastIRInstruction::Assign { value, .. } => {
+                substitute(value, &folded);
+            }
+            ZastIRInstruction::BinaryOp {
+                dest,
+                op,
+                left,
+                right,
+                val_type,
+                span,
+            } => {
+                substitute(left, &folded);
+                substitute(right, &folded);
+
+                if let Some(result) = eval_binary(op, left, right, val_type, *span, config, errors)
+                {
+                    folded.insert(*dest, result);
+                    return false;
+                }
+            }
+            ZastIRInstruction::UnaryOp { operand, .. } => substitute(operand, &folded),
+            ZastIRInstruction::FunctionDecl { body, .. } => fold_instructions(body, config, errors),
+            ZastIRInstruction::Call { args, .. } => {
+                for arg in args {
+                    substitute(arg, &folded);
+                }
+            }
+            ZastIRInstruction::Return(value) => {
+                if let Some(value) = value {
+                    substitute(value, &folded);
+                }
+            }
+            ZastIRInstruction::BranchIf { cond, .. } => substitute(cond, &folded),
+            ZastIRInstruction::DeclareUninit { .. }
+            | ZastIRInstruction::Label(_)
+            | ZastIRInstruction::Jump(_) => {}
+        }
+
+        true
+    });
+}
+
+/// Removes pure `BinaryOp` and `UnaryOp` instructions whose temporary is never
+/// read, e.g. the value of a discarded expression statement.
+///
+/// Runs to a fixed point, so operands that were only feeding a removed
+/// instruction are removed as well. Calls are always kept for their side
+/// effects, even when their result is unused.
+///
+/// ```rust
+/// use zast::{
+///     parser::ZastParser,
+///     zast_ir::{
+///         ZastIREmitter, ir_instructions::ZastIRInstruction, ir_passes::eliminate_dead_temporaries,
+///     },
+/// };
+///
+/// let src = "fn g(): i32 { return 1; }\nfn f(a: i32, b: i32): void { a + b; g(); }";
+/// let program = ZastParser::from_source(src).unwrap();
+/// let mut ir = ZastIREmitter::new().emit(&program).unwrap();
+/// eliminate_dead_temporaries(&mut ir);
+///
+/// let ZastIRInstruction::FunctionDecl { body, .. } = &ir.instructions[1] else {
+///     unreachable!()
+/// };
+/// assert!(matches!(&body[..], [ZastIRInstruction::Call { name, .. }] if name == "g"));
+/// ```
+pub fn eliminate_dead_temporaries(program: &mut ZastIRProgram) {
+    for instr in &mut program.instructions {
+        if let ZastIRInstruction::FunctionDecl { body, .. } = instr {
+            eliminate_dead_in(body);
+        }
+    }
+}
+
+fn eliminate_dead_in(instructions: &mut Vec<ZastIRInstruction>) {
+    loop {
+        let mut used = HashSet::new();
+        for instr in instructions.iter_mut() {
+            match instr {
+                ZastIRInstruction::FunctionDecl { body, .. } => eliminate_dead_in(body),
+                instr => collect_temporaries(instr, &mut used),
+            }
+        }
+
+        let before = instructions.len();
+        instructions.retain(|instr| match instr {
+            ZastIRInstruction::BinaryOp { dest, .. } | ZastIRInstruction::UnaryOp { dest, .. } => {
+                used.contains(dest)
+            }
+            _ => true,
+        });
+
+        if instructions.len() == before {
+            break;
+        }
+    }
+}
+
+fn collect_temporaries(instr: &ZastIRInstruction, used: &mut HashSet<usize>) {
+    let mut mark = |value: &ZastIRValue| {
+        if let ZastIRValue::Temporary(n) = value {
+            used.insert(*n);
+        }
+    };
+
+    match instr {
+        ZastIRInstruction::Declare { value, .. } | ZastIRInstruction::Assign { value, .. } => {
+            mark(value)
+        }
+        ZastIRInstruction::BinaryOp { left, right, .. } => {
+            mark(left);
+            mark(right);
+        }
+        ZastIRInstruction::UnaryOp { operand, .. } => mark(operand),
+        ZastIRInstruction::Call { args, .. } => args.iter().for_each(mark),
+        ZastIRInstruction::Return(value) => {
+            if let Some(value) = value {
+                mark(value);
+            }
+        }
+        ZastIRInstruction::BranchIf { cond, .. } => mark(cond),
+        ZastIRInstruction::FunctionDecl { .. }
+        | ZastIRInstruction::DeclareUninit { .. }
+        | ZastIRInstruction::Label(_)
+        | ZastIRInstruction::Jump(_) => {}
+    }
+}
+
+fn substitute(value: &mut ZastIRValue, folded: &HashMap<usize, ZastIRValue>) {
+    if let ZastIRValue::Temporary(n) = value
+        && let Some(constant) = folded.get(n)
+    {
+        *value = constant.clone();
+    }
+}
+
+/// Evaluates `left <op> right`, returning `None` to leave the operation in
+/// place. `val_type` is the type of the result.
+fn eval_binary(
+    op: &BinaryOp,
+    left: &ZastIRValue,
+    right: &ZastIRValue,
+    val_type: &ValueType,
+    span: Option<Span>,
+    config: &ZastConfig,
+    errors: &mut ZastErrorCollector,
+) -> Option<ZastIRValue> {
+    match (left, right) {
+        (ZastIRValue::Int(l), ZastIRValue::Int(r)) => {
+            let (l, r) = (*l, *r);
+            let value = match op {
+                BinaryOp::Eq => ZastIRValue::Bool(l == r),
+                BinaryOp::NotEq => ZastIRValue::Bool(l != r),
+                BinaryOp::Lt => ZastIRValue::Bool(l < r),
+                BinaryOp::LtEq => ZastIRValue::Bool(l <= r),
+                BinaryOp::Gt => ZastIRValue::Bool(l > r),
+                BinaryOp::GtEq => ZastIRValue::Bool(l >= r),
+                BinaryOp::And | BinaryOp::Or => return None,
+                BinaryOp::Div if r == 0 => {
+                    errors.add_error(ZastError::DivisionByZero {
+                        span: span.unwrap_or_default(),
+                    });
+                    return None;
+                }
+                _ => return eval_int_arithmetic(op, l, r, val_type, span, config, errors),
+            };
+            Some(value)
+        }
+        (ZastIRValue::Float(l), ZastIRValue::Float(r)) => {
+            let (l, r) = (*l, *r);
+            let value = match op {
+                BinaryOp::Add => float_result(l + r, val_type),
+                BinaryOp::Sub => float_result(l - r, val_type),
+                BinaryOp::Mul => float_result(l * r, val_type),
+                BinaryOp::Div => float_result(l / r, val_type),
+                BinaryOp::Pow => float_result(l.powf(r), val_type),
+                BinaryOp::Eq => ZastIRValue::Bool(l == r),
+                BinaryOp::NotEq => ZastIRValue::Bool(l != r),
+                BinaryOp::Lt => ZastIRValue::Bool(l < r),
+                BinaryOp::LtEq => ZastIRValue::Bool(l <= r),
+                BinaryOp::Gt => ZastIRValue::Bool(l > r),
+                BinaryOp::GtEq => ZastIRValue::Bool(l >= r),
+                BinaryOp::And
+                | BinaryOp::Or
+                | BinaryOp::BitAnd
+                | BinaryOp::BitXor
+                | BinaryOp::BitOr
+                | BinaryOp::Shl
+                | BinaryOp::Shr => return None,
+            };
+            Some(value)
+        }
+        (ZastIRValue::Bool(l), ZastIRValue::Bool(r)) => match op {
+            BinaryOp::And => Some(ZastIRValue::Bool(*l && *r)),
+            BinaryOp::Or => Some(ZastIRValue::Bool(*l || *r)),
+            BinaryOp::Eq => Some(ZastIRValue::Bool(l == r)),
+            BinaryOp::NotEq => Some(ZastIRValue::Bool(l != r)),
+            _ => None,
+        },
+        _ => None,
+    }
+}
+
+/// Folds integer arithmetic, bitwise, and shift operations for the integer
+/// type `val_type`, falling back to `i64` when the type is unknown.
+fn eval_int_arithmetic(
+    op: &BinaryOp,
+    l: i64,
+    r: i64,
+    val_type: &ValueType,
+    span: Option<Span>,
+    config: &ZastConfig,
+    errors: &mut ZastErrorCollector,
+) -> Option<ZastIRValue> {
+    let pointer_width = config.target_pointer_width;
+    let bits = val_type.int_bit_width(pointer_width).unwrap_or(64);
+    let (min, max) = val_type
+        .int_range(pointer_width)
+        .unwrap_or((i64::MIN.into(), i64::MAX.into()));
+    let (wide_l, wide_r) = (i128::from(l), i128::from(r));
+    // shift amounts at or past the width overflow, and wrap modulo the width
+    let shift = u32::try_from(r)
+        .ok()
+        .filter(|amount| *amount < u32::from(bits));
+    let wrapped_shift = r.rem_euclid(i64::from(bits)) as u32;
+
+    // the exact result, or `None` if even `i128` can't hold it, next to the
+    // result modulo 2^64
+    let (exact, wrapped) = match op {
+        BinaryOp::Add => (Some(wide_l + wide_r), l.wrapping_add(r)),
+        BinaryOp::Sub => (Some(wide_l - wide_r), l.wrapping_sub(r)),
+        BinaryOp::Mul => (Some(wide_l * wide_r), l.wrapping_mul(r)),
+        BinaryOp::Div => (Some(wide_l / wide_r), l.wrapping_div(r)),
+        BinaryOp::Pow => {
+            let exp = u32::try_from(r).ok()?;
+            (wide_l.checked_pow(exp), l.wrapping_pow(exp))
+        }
+        BinaryOp::BitAnd => (Some(wide_l & wide_r), l & r),
+        BinaryOp::BitXor => (Some(wide_l ^ wide_r), l ^ r),
+        BinaryOp::BitOr => (Some(wide_l | wide_r), l | r),
+        BinaryOp::Shl => (
+            shift.map(|amount| wide_l << amount),
+            l.wrapping_shl(wrapped_shift),
+        ),
+        BinaryOp::Shr => (
+            shift.map(|amount| wide_l >> amount),
+            l.wrapping_shr(wrapped_shift),
+        ),
+        _ => return None,
+    };
+
+    let value = match exact {
+        Some(value) if (min..=max).contains(&value) => value,
+        _ if config.checked_arithmetic => {
+            errors.add_error(ZastError::ArithmeticOverflow {
+                span: span.unwrap_or_default(),
+                ty: val_type.clone(),
+            });
+            return None;
+        }
+        _ => (i128::from(wrapped) - min).rem_euclid(max - min + 1) + min,
+    };
+
+    // `u64` values past `i64::MAX` have no `Int` representation
+    Some(ZastIRValue::Int(i64::try_from(value).ok()?))
+}
+
+/// Rounds a folded float to the precision of `val_type`, so `f32` arithmetic
+/// doesn't fold at `f64` precision.
+fn float_result(value: f64, val_type: &ValueType) -> ZastIRValue {
+    match val_type {
+        ValueType::Float {
+            width: FloatWidth::F32,
+        } => ZastIRValue::Float(value as f32 as f64),
+        _ => ZastIRValue::Float(value),
+    }
+}
@@ -1,8 +1,27 @@
+use core::fmt;
+
+#[derive(Debug, Clone, PartialEq)]
 pub enum ZastIRValue {
     Int(i64),
     Float(f64),
     Bool(bool),
+    Str(String),
     Reference(String),
     Temporary(usize),
     Null,
 }
+
+/// Renders constants as literals, temporaries as `%N`, and `Null` as `null`.
+impl fmt::Display for ZastIRValue {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Int(value) => write!(f, "{}", value),
+            Self::Float(value) => write!(f, "{:?}", value),
+            Self::Bool(value) => write!(f, "{}", value),
+            Self::Str(value) => write!(f, "{:?}", value),
+            Self::Reference(name) => write!(f, "{}", name),
+            Self::Temporary(n) => write!(f, "%{}", n),
+            Self::Null => write!(f, "null"),
+        }
+    }
+}